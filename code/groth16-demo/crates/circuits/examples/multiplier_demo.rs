@@ -70,13 +70,11 @@ fn main() {
     // Step 5: Convert R1CS to QAP
     println!("Step 5: Converting R1CS to QAP");
     println!("----------------------------");
-    // Note: Need at least 2 constraints for QAP transformation
-    // For this demo with 1 constraint, we'll show what would happen
-
-    // Create a second constraint to enable QAP transformation
-    // This demonstrates the QAP transformation with multiple constraints
-    println!("Note: QAP transformation requires at least 2 constraints.");
-    println!("For demonstration, let's create a second constraint...");
+    // Note: Interpolation needs at least 2 points, so r1cs_to_qap pads a
+    // single constraint with a duplicate of itself. We add the duplicate
+    // explicitly here so the padding is visible.
+    println!("Note: QAP interpolation needs at least 2 constraints.");
+    println!("A single constraint is padded with a copy of itself; let's do that explicitly...");
     println!();
 
     // Create a second identical constraint (same computation)
@@ -0,0 +1,170 @@
+use crate::error::CircuitError;
+use ark_bn254::Fr;
+use groth16::{generate_proof, trusted_setup, verify_proof, Groth16Error};
+use groth16_math::fields::FieldWrapper;
+use groth16_qap::r1cs_to_qap;
+use groth16_r1cs::constraint::R1CSConstraint;
+
+/// Common interface for circuits that can be run through Groth16.
+///
+/// Implementors describe their constraints and a satisfying witness in the
+/// standard layout [1, public_inputs..., private_inputs...]. Anything
+/// implementing `Circuit` can be proven with [`prove_and_verify`] without
+/// circuit-specific glue.
+pub trait Circuit {
+    /// Converts the circuit to R1CS constraints.
+    fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>>;
+
+    /// Generates the full witness assignment, starting with the constant 1.
+    fn witness(&self) -> Vec<FieldWrapper<Fr>>;
+
+    /// Number of public inputs, not counting the constant 1.
+    ///
+    /// These are the witness entries at indices 1..=num_public_inputs.
+    fn num_public_inputs(&self) -> usize;
+
+    /// Witness indices holding the public inputs.
+    ///
+    /// Follows the [1, public_inputs..., private_inputs...] layout, so this is
+    /// `1..num_public_inputs + 1`. Its length is the `num_inputs` to pass to
+    /// `trusted_setup`, and slicing the witness with it yields the values
+    /// `verify_proof` expects.
+    fn public_input_indices(&self) -> std::ops::Range<usize> {
+        1..self.num_public_inputs() + 1
+    }
+
+    /// Checks that the witness covers exactly the variables the constraints use.
+    ///
+    /// The witness length must be one more than the highest variable index
+    /// referenced by any constraint. Catching a mismatch here gives a clear
+    /// error before proving, instead of an `InvalidWitnessLength` deep inside
+    /// `generate_proof`.
+    fn validate(&self) -> Result<(), CircuitError> {
+        let expected = self
+            .to_r1cs()
+            .iter()
+            .flat_map(|c| c.a.keys().chain(c.b.keys()).chain(c.c.keys()))
+            .max()
+            .map_or(0, |max_index| max_index + 1);
+        let actual = self.witness().len();
+
+        if expected != actual {
+            return Err(CircuitError::WitnessLengthMismatch { expected, actual });
+        }
+        Ok(())
+    }
+}
+
+/// Runs a circuit through the full pipeline: R1CS → QAP → setup → prove → verify.
+///
+/// Uses fresh randomness from the thread RNG for the setup and the proof,
+/// so this is a convenience for demos and tests rather than a real
+/// deployment (where the setup happens once, separately from proving).
+///
+/// # Returns
+/// * `Ok(true)` - The generated proof verified against the circuit's public inputs
+/// * `Ok(false)` - The proof did not verify
+/// * `Err(Groth16Error)` - A stage of the pipeline failed
+pub fn prove_and_verify<C: Circuit>(circuit: &C) -> Result<bool, Groth16Error> {
+    if let Err(CircuitError::WitnessLengthMismatch { expected, actual }) = circuit.validate() {
+        return Err(Groth16Error::InvalidWitnessLength { expected, actual });
+    }
+
+    let constraints = circuit.to_r1cs();
+    let witness = circuit.witness();
+    let num_inputs = circuit.num_public_inputs();
+
+    let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, witness.len())?;
+
+    let mut rng = rand::thread_rng();
+    let (pk, vk) = trusted_setup(
+        &a_polys,
+        &b_polys,
+        &c_polys,
+        constraints.len(),
+        num_inputs,
+        &mut rng,
+    )?;
+    let proof = generate_proof(
+        &pk, &witness, &a_polys, &b_polys, &c_polys, num_inputs, &mut rng,
+    )?;
+
+    verify_proof(&vk, &proof, &witness[circuit.public_input_indices()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::CubicCircuit;
+
+    #[test]
+    fn test_cubic_end_to_end() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+        assert!(prove_and_verify(&circuit).unwrap());
+
+        // A witness that breaks the constraints yields a proof that fails
+        let wrong = CubicCircuit::new(2, 3, 4, 5, 2, 40);
+        assert!(!prove_and_verify(&wrong).unwrap());
+    }
+
+    /// A circuit whose witness omits its last variable
+    struct TruncatedWitnessCircuit;
+
+    impl Circuit for TruncatedWitnessCircuit {
+        fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>> {
+            CubicCircuit::new(2, 3, 4, 5, 2, 41).to_r1cs()
+        }
+
+        fn witness(&self) -> Vec<FieldWrapper<Fr>> {
+            let mut witness = CubicCircuit::new(2, 3, 4, 5, 2, 41).witness();
+            witness.pop();
+            witness
+        }
+
+        fn num_public_inputs(&self) -> usize {
+            5
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(CubicCircuit::new(2, 3, 4, 5, 2, 41).validate().is_ok());
+        assert!(crate::multiplier::MultiplierCircuit::new(3, 4, 12)
+            .validate()
+            .is_ok());
+
+        assert!(matches!(
+            TruncatedWitnessCircuit.validate(),
+            Err(CircuitError::WitnessLengthMismatch {
+                expected: 12,
+                actual: 11
+            })
+        ));
+
+        // The pipeline refuses to prove a malformed circuit
+        assert!(matches!(
+            prove_and_verify(&TruncatedWitnessCircuit),
+            Err(Groth16Error::InvalidWitnessLength {
+                expected: 12,
+                actual: 11
+            })
+        ));
+    }
+
+    #[test]
+    fn test_single_constraint_multiplier_end_to_end() {
+        use groth16_qap::{check_divisibility, target_polynomial};
+
+        // The multiplier has exactly one constraint; r1cs_to_qap pads it
+        let circuit = crate::multiplier::MultiplierCircuit::new(3, 4, 12);
+        let constraints = Circuit::to_r1cs(&circuit);
+        assert_eq!(constraints.len(), 1);
+
+        let witness = Circuit::witness(&circuit);
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, witness.len()).unwrap();
+        let target = target_polynomial::<Fr>(2);
+        assert!(check_divisibility(&witness, &a_polys, &b_polys, &c_polys, &target).unwrap());
+
+        assert!(prove_and_verify(&circuit).unwrap());
+    }
+}
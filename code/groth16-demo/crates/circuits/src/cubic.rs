@@ -1 +1,279 @@
+use crate::circuit::Circuit;
+use ark_bn254::Fr;
+use groth16_math::fields::FieldWrapper;
+use groth16_r1cs::constraint::R1CSConstraint;
 
+/// Cubic polynomial circuit: a·x³ + b·x² + c·x + d = y
+///
+/// Proves knowledge of a root-like value x for a publicly known cubic,
+/// without revealing x.
+///
+/// # Privacy
+/// - **Public inputs**: a, b, c, d (the coefficients) and y (the result)
+/// - **Private input**: x
+///
+/// # R1CS Representation
+/// Six constraints over 12 variables:
+///
+/// ```text
+/// [1, a, b, c, d, y, x, x², x³, a·x³, b·x², c·x]
+///  0  1  2  3  4  5  6  7   8   9     10    11
+///
+/// x  · x  = x²
+/// x² · x  = x³
+/// a  · x³ = a·x³
+/// b  · x² = b·x²
+/// c  · x  = c·x
+/// (a·x³ + b·x² + c·x + d) · 1 = y
+/// ```
+///
+/// The coefficients are variables rather than constants baked into the
+/// constraints, so one setup serves every cubic.
+///
+/// # Example
+/// ```rust
+/// use groth16_circuits::cubic::CubicCircuit;
+///
+/// // 2x³ + 3x² + 4x + 5 at x = 2 is 16 + 12 + 8 + 5 = 41
+/// let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+/// assert!(circuit.verify());
+///
+/// let constraints = circuit.to_r1cs();
+/// let witness = circuit.witness();
+/// assert!(constraints.iter().all(|c| c.is_satisfied(&witness)));
+/// ```
+pub struct CubicCircuit {
+    /// Public coefficient of x³
+    pub a: u64,
+    /// Public coefficient of x²
+    pub b: u64,
+    /// Public coefficient of x
+    pub c: u64,
+    /// Public constant term
+    pub d: u64,
+    /// Private input x
+    pub x: u64,
+    /// Public result y
+    pub y: u64,
+}
+
+impl CubicCircuit {
+    /// Number of variables in the witness, including the constant 1
+    pub const NUM_VARIABLES: usize = 12;
+
+    /// Creates a new cubic circuit instance.
+    ///
+    /// # Note
+    /// This function does NOT verify that the polynomial evaluates to y.
+    /// Use `verify()` to check the computation.
+    pub fn new(a: u64, b: u64, c: u64, d: u64, x: u64, y: u64) -> Self {
+        Self { a, b, c, d, x, y }
+    }
+
+    /// Converts the circuit to R1CS constraints.
+    ///
+    /// # Returns
+    /// A vector of six R1CS constraints (see the type-level docs for layout)
+    pub fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>> {
+        let one = || FieldWrapper::<Fr>::from(1u64);
+
+        // x · x = x²
+        let mut square = R1CSConstraint::<Fr>::new();
+        square.add_a_variable(6, one());
+        square.add_b_variable(6, one());
+        square.add_c_variable(7, one());
+
+        // x² · x = x³
+        let mut cube = R1CSConstraint::<Fr>::new();
+        cube.add_a_variable(7, one());
+        cube.add_b_variable(6, one());
+        cube.add_c_variable(8, one());
+
+        // a · x³ = a·x³
+        let mut a_term = R1CSConstraint::<Fr>::new();
+        a_term.add_a_variable(1, one());
+        a_term.add_b_variable(8, one());
+        a_term.add_c_variable(9, one());
+
+        // b · x² = b·x²
+        let mut b_term = R1CSConstraint::<Fr>::new();
+        b_term.add_a_variable(2, one());
+        b_term.add_b_variable(7, one());
+        b_term.add_c_variable(10, one());
+
+        // c · x = c·x
+        let mut c_term = R1CSConstraint::<Fr>::new();
+        c_term.add_a_variable(3, one());
+        c_term.add_b_variable(6, one());
+        c_term.add_c_variable(11, one());
+
+        // (a·x³ + b·x² + c·x + d) · 1 = y
+        let mut sum = R1CSConstraint::<Fr>::new();
+        sum.add_a_variable(9, one());
+        sum.add_a_variable(10, one());
+        sum.add_a_variable(11, one());
+        sum.add_a_variable(4, one());
+        sum.add_b_variable(0, one());
+        sum.add_c_variable(5, one());
+
+        vec![square, cube, a_term, b_term, c_term, sum]
+    }
+
+    /// Generates the witness assignment for this circuit instance.
+    ///
+    /// Follows the standard Groth16 convention [1, public..., private...]:
+    /// [1, a, b, c, d, y, x, x², x³, a·x³, b·x², c·x]
+    ///
+    /// # Returns
+    /// Vector of field elements representing the witness
+    pub fn witness(&self) -> Vec<FieldWrapper<Fr>> {
+        let a = Fr::from(self.a);
+        let b = Fr::from(self.b);
+        let c = Fr::from(self.c);
+        let x = Fr::from(self.x);
+        let x2 = x * x;
+        let x3 = x2 * x;
+
+        vec![
+            FieldWrapper::<Fr>::from(1u64),   // constant 1
+            FieldWrapper::<Fr>::from(self.a), // public a
+            FieldWrapper::<Fr>::from(self.b), // public b
+            FieldWrapper::<Fr>::from(self.c), // public c
+            FieldWrapper::<Fr>::from(self.d), // public d
+            FieldWrapper::<Fr>::from(self.y), // public y
+            FieldWrapper::<Fr>::from(self.x), // private x
+            FieldWrapper::<Fr>::from(x2),     // x²
+            FieldWrapper::<Fr>::from(x3),     // x³
+            FieldWrapper::<Fr>::from(a * x3), // a·x³
+            FieldWrapper::<Fr>::from(b * x2), // b·x²
+            FieldWrapper::<Fr>::from(c * x),  // c·x
+        ]
+    }
+
+    /// Verifies that a·x³ + b·x² + c·x + d = y in the field.
+    pub fn verify(&self) -> bool {
+        let x = Fr::from(self.x);
+        let result = Fr::from(self.a) * x * x * x
+            + Fr::from(self.b) * x * x
+            + Fr::from(self.c) * x
+            + Fr::from(self.d);
+
+        result == Fr::from(self.y)
+    }
+}
+
+impl Circuit for CubicCircuit {
+    fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>> {
+        CubicCircuit::to_r1cs(self)
+    }
+
+    fn witness(&self) -> Vec<FieldWrapper<Fr>> {
+        CubicCircuit::witness(self)
+    }
+
+    fn num_public_inputs(&self) -> usize {
+        5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_valid() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+        assert!(circuit.verify());
+    }
+
+    #[test]
+    fn test_circuit_invalid() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 42);
+        assert!(!circuit.verify());
+    }
+
+    #[test]
+    fn test_witness_layout() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+        let witness = circuit.witness();
+
+        assert_eq!(witness.len(), CubicCircuit::NUM_VARIABLES);
+        assert_eq!(witness[5].value, Fr::from(41u64)); // public y
+        assert_eq!(witness[6].value, Fr::from(2u64)); // private x
+        assert_eq!(witness[8].value, Fr::from(8u64)); // x³
+    }
+
+    #[test]
+    fn test_r1cs_satisfied() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+        let constraints = circuit.to_r1cs();
+        let witness = circuit.witness();
+
+        assert_eq!(constraints.len(), 6);
+        assert!(constraints.iter().all(|c| c.is_satisfied(&witness)));
+    }
+
+    #[test]
+    fn test_r1cs_not_satisfied() {
+        // Claimed y is wrong, so the final sum constraint fails
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 42);
+        let constraints = circuit.to_r1cs();
+        let witness = circuit.witness();
+
+        assert!(!constraints[5].is_satisfied(&witness));
+    }
+
+    #[test]
+    fn test_public_input_indices() {
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+
+        // a, b, c, d and y are public
+        assert_eq!(circuit.num_public_inputs(), 5);
+        assert_eq!(circuit.public_input_indices(), 1..6);
+
+        let witness = circuit.witness();
+        let public = &witness[circuit.public_input_indices()];
+        assert_eq!(public.len(), 5);
+        assert_eq!(public[0].value, Fr::from(2u64)); // a
+        assert_eq!(public[4].value, Fr::from(41u64)); // y
+    }
+
+    #[test]
+    fn test_r1cs_qap_consistency() {
+        use groth16_qap::verify_r1cs_qap_consistency;
+
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+        let constraints = circuit.to_r1cs();
+        let witness = circuit.witness();
+        assert!(
+            verify_r1cs_qap_consistency(&constraints, CubicCircuit::NUM_VARIABLES, &witness)
+                .unwrap()
+        );
+
+        // Break the intermediate x² so the first two constraints fail
+        let mut broken = witness;
+        broken[7] = FieldWrapper::<Fr>::from(5u64);
+        assert!(
+            !verify_r1cs_qap_consistency(&constraints, CubicCircuit::NUM_VARIABLES, &broken)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_r1cs_matches_json_testdata() {
+        use groth16_r1cs::load_r1cs_json;
+        use groth16_r1cs::system::ConstraintSystem;
+
+        // The r1cs crate's JSON tests run against this exported layout
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../r1cs/testdata/cubic.json");
+        let (constraints, num_variables) = load_r1cs_json::<Fr>(path).unwrap();
+        let circuit = CubicCircuit::new(2, 3, 4, 5, 2, 41);
+
+        assert_eq!(num_variables, CubicCircuit::NUM_VARIABLES);
+        assert!(constraints == circuit.to_r1cs());
+
+        let system = ConstraintSystem::new(circuit.to_r1cs(), CubicCircuit::NUM_VARIABLES);
+        let exported = ConstraintSystem::<Fr>::from_json_r1cs(&system.to_json_r1cs()).unwrap();
+        assert!(exported.constraints == constraints);
+    }
+}
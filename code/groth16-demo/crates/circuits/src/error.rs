@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+/// Errors that can occur while building circuits and witnesses
+#[derive(Error, Debug)]
+pub enum CircuitError {
+    #[error("Invalid witness: {0}")]
+    InvalidWitness(String),
+
+    #[error(
+        "Witness length mismatch: constraints need {expected} variables, witness has {actual}"
+    )]
+    WitnessLengthMismatch { expected: usize, actual: usize },
+}
@@ -7,8 +7,13 @@
 //! - Merkle tree membership
 //! - Range proof
 
+pub mod circuit;
 pub mod cubic;
+pub mod error;
 pub mod hash_preimage;
 pub mod merkle;
 pub mod multiplier;
 pub mod range_proof;
+
+pub use circuit::{prove_and_verify, Circuit};
+pub use error::CircuitError;
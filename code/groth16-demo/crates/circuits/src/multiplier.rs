@@ -1,4 +1,7 @@
+use crate::circuit::Circuit;
+use crate::error::CircuitError;
 use ark_bn254::Fr;
+use ark_ff::PrimeField;
 use groth16_math::fields::FieldWrapper;
 use groth16_r1cs::constraint::R1CSConstraint;
 
@@ -74,16 +77,25 @@ impl MultiplierCircuit {
     /// # Returns
     /// A vector containing one R1CS constraint
     pub fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>> {
-        let mut constraint = R1CSConstraint::<Fr>::new();
+        self.to_r1cs_over::<Fr>()
+    }
+
+    /// Converts the circuit to R1CS constraints over any prime field.
+    ///
+    /// Same constraint as [`to_r1cs`](Self::to_r1cs), which is this with
+    /// BN254's `Fr`. Use it to run the circuit on another curve, e.g.
+    /// `to_r1cs_over::<ark_bls12_381::Fr>()`.
+    pub fn to_r1cs_over<F: PrimeField>(&self) -> Vec<R1CSConstraint<F>> {
+        let mut constraint = R1CSConstraint::<F>::new();
 
         // A vector: selects variable a (index 2, after 1 and c)
-        constraint.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        constraint.add_a_variable(2, FieldWrapper::<F>::from(1u64));
 
         // B vector: selects variable b (index 3)
-        constraint.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        constraint.add_b_variable(3, FieldWrapper::<F>::from(1u64));
 
         // C vector: selects variable c (index 1, the public output)
-        constraint.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+        constraint.add_c_variable(1, FieldWrapper::<F>::from(1u64));
 
         vec![constraint]
     }
@@ -107,14 +119,47 @@ impl MultiplierCircuit {
     /// # Returns
     /// Vector of field elements representing the witness
     pub fn witness(&self) -> Vec<FieldWrapper<Fr>> {
+        self.witness_over::<Fr>()
+    }
+
+    /// Generates the witness [1, c, a, b] over any prime field.
+    ///
+    /// The field counterpart of [`to_r1cs_over`](Self::to_r1cs_over).
+    pub fn witness_over<F: PrimeField>(&self) -> Vec<FieldWrapper<F>> {
         vec![
-            FieldWrapper::<Fr>::from(1u64),   // constant 1
-            FieldWrapper::<Fr>::from(self.c), // public output c
-            FieldWrapper::<Fr>::from(self.a), // private input a
-            FieldWrapper::<Fr>::from(self.b), // private input b
+            FieldWrapper::<F>::from(1u64),   // constant 1
+            FieldWrapper::<F>::from(self.c), // public output c
+            FieldWrapper::<F>::from(self.a), // private input a
+            FieldWrapper::<F>::from(self.b), // private input b
         ]
     }
 
+    /// Builds the witness [1, c, a, b], checking that a × b = c first.
+    ///
+    /// Prefer this over assembling the witness by hand: a wrong `c` would
+    /// otherwise only show up as a proof that fails to verify.
+    ///
+    /// # Errors
+    /// * `CircuitError::InvalidWitness` - If a × b ≠ c in the field
+    pub fn witness_checked(a: u64, b: u64, c: u64) -> Result<Vec<FieldWrapper<Fr>>, CircuitError> {
+        let circuit = Self::new(a, b, c);
+        if !circuit.verify() {
+            return Err(CircuitError::InvalidWitness(format!(
+                "{} × {} ≠ {}",
+                a, b, c
+            )));
+        }
+        Ok(circuit.witness())
+    }
+
+    /// Returns the public inputs of this instance, without the constant 1.
+    ///
+    /// For the multiplier circuit this is just [c]. Wrap it in a
+    /// `groth16::PublicInput` to ship alongside a proof instead of the witness.
+    pub fn public_inputs(&self) -> Vec<FieldWrapper<Fr>> {
+        vec![FieldWrapper::<Fr>::from(self.c)]
+    }
+
     /// Verifies that the circuit computation is correct (a × b = c).
     ///
     /// # Returns
@@ -130,6 +175,20 @@ impl MultiplierCircuit {
     }
 }
 
+impl Circuit for MultiplierCircuit {
+    fn to_r1cs(&self) -> Vec<R1CSConstraint<Fr>> {
+        MultiplierCircuit::to_r1cs(self)
+    }
+
+    fn witness(&self) -> Vec<FieldWrapper<Fr>> {
+        MultiplierCircuit::witness(self)
+    }
+
+    fn num_public_inputs(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +228,15 @@ mod tests {
         assert_eq!(witness[3].value, Fr::from(4u64)); // private input b
     }
 
+    #[test]
+    fn test_witness_checked() {
+        let witness = MultiplierCircuit::witness_checked(3, 4, 12).unwrap();
+        assert_eq!(witness[1].value, Fr::from(12u64));
+
+        let result = MultiplierCircuit::witness_checked(3, 4, 13);
+        assert!(matches!(result, Err(CircuitError::InvalidWitness(_))));
+    }
+
     #[test]
     fn test_r1cs_satisfied() {
         let circuit = MultiplierCircuit::new(3, 4, 12);
@@ -179,6 +247,90 @@ mod tests {
         assert!(constraints[0].is_satisfied(&witness));
     }
 
+    #[test]
+    fn test_verify_with_public_input_only() {
+        use groth16::{generate_proof_test, trusted_setup_test, verify_proof, PublicInput};
+        use groth16_qap::r1cs_to_qap;
+
+        let circuit = MultiplierCircuit::new(3, 4, 12);
+        let constraints = vec![circuit.to_r1cs()[0].clone(), circuit.to_r1cs()[0].clone()];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        let proof = {
+            let witness = circuit.witness();
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap()
+        };
+
+        // Only the public part leaves the prover; the witness is out of scope
+        let public = PublicInput::new(circuit.public_inputs());
+        assert_eq!(public.len(), 1);
+
+        assert!(verify_proof(&vk, &proof, public.inputs()).unwrap());
+
+        // A different claimed output does not verify
+        let other = PublicInput::new(MultiplierCircuit::new(3, 5, 15).public_inputs());
+        assert!(!verify_proof(&vk, &proof, other.inputs()).unwrap());
+    }
+
+    /// Runs the multiplier through setup, proving and verification on the
+    /// curve whose scalar field is `F`
+    fn prove_and_verify_over<F: groth16_math::pairing::CurveScalar>() {
+        use groth16::{generate_proof_test, trusted_setup_test, verify_proof};
+        use groth16_qap::r1cs_to_qap;
+
+        let circuit = MultiplierCircuit::new(3, 4, 12);
+        let constraints = circuit.to_r1cs_over::<F>();
+        let witness = circuit.witness_over::<F>();
+        assert!(constraints[0].is_satisfied(&witness));
+
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, witness.len()).unwrap();
+        let seed = [7u8; 32];
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        let proof =
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+
+        assert!(verify_proof(&vk, &proof, &witness[1..2]).unwrap());
+
+        // The proof does not verify for another product
+        let tampered = [FieldWrapper::<F>::from(13u64)];
+        assert!(!verify_proof(&vk, &proof, &tampered).unwrap());
+    }
+
+    #[test]
+    fn test_end_to_end_on_bn254_and_bls12_381() {
+        prove_and_verify_over::<Fr>();
+        prove_and_verify_over::<ark_bls12_381::Fr>();
+    }
+
+    #[test]
+    fn test_prove_from_json_r1cs() {
+        use groth16::{generate_proof_test, trusted_setup_test, verify_proof};
+        use groth16_qap::r1cs_to_qap;
+        use groth16_r1cs::load_r1cs_json;
+
+        // The same circuit, defined as data instead of code
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../r1cs/testdata/multiplier.json"
+        );
+        let (constraints, num_variables) = load_r1cs_json::<Fr>(path).unwrap();
+        assert!(constraints == MultiplierCircuit::new(3, 4, 12).to_r1cs());
+
+        let witness = MultiplierCircuit::new(3, 4, 12).witness();
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, num_variables).unwrap();
+        let seed = [42u8; 32];
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        let proof =
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+
+        assert!(verify_proof(&vk, &proof, &witness[1..2]).unwrap());
+    }
+
     #[test]
     fn test_r1cs_not_satisfied() {
         // Create circuit with wrong result
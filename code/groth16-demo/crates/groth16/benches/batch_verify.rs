@@ -38,7 +38,8 @@ fn setup_batch(
 
     // Setup with 1 public input (c)
     let seed = [42u8; 32];
-    let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+    let (pk, vk) =
+        trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
     // Generate multiple proofs with different witnesses
     let proofs_and_inputs: Vec<_> = (0..size)
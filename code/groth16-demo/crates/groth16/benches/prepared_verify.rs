@@ -0,0 +1,59 @@
+//! Benchmarks for verification with a prepared verification key
+//!
+//! Run with: cargo bench --package groth16 --bench prepared_verify
+//!
+//! Verifies the same proof 100 times, once through `verify_proof` and once
+//! through `verify_proof_prepared`. The prepared path skips the e(α, β)
+//! pairing and the G₂ preparation of γ and δ on every call.
+
+use ark_bn254::Fr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use groth16::{generate_proof_test, trusted_setup_test, verify_proof, verify_proof_prepared};
+use groth16_math::fields::FieldWrapper;
+use groth16_qap::r1cs_to_qap;
+use groth16_r1cs::constraint::R1CSConstraint;
+
+const NUM_VERIFICATIONS: usize = 100;
+
+fn bench_prepared_verify(c: &mut Criterion) {
+    // a × b = c with witness [1, c, a, b]
+    let mut constraint = R1CSConstraint::<Fr>::new();
+    constraint.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+    constraint.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+    constraint.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+    let (a_polys, b_polys, c_polys) = r1cs_to_qap(&[constraint.clone(), constraint], 4).unwrap();
+
+    let seed = [42u8; 32];
+    let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 2, 1, &seed).unwrap();
+    let witness: Vec<_> = [1u64, 12, 3, 4]
+        .into_iter()
+        .map(FieldWrapper::<Fr>::from)
+        .collect();
+    let proof = generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+    let public_inputs = vec![FieldWrapper::<Fr>::from(12u64)];
+
+    let mut group = c.benchmark_group("verify_100_proofs");
+    group.sample_size(10);
+
+    group.bench_function("standard", |b| {
+        b.iter(|| {
+            for _ in 0..NUM_VERIFICATIONS {
+                black_box(verify_proof(&vk, &proof, &public_inputs).unwrap());
+            }
+        });
+    });
+
+    let pvk = vk.clone().prepare();
+    group.bench_function("prepared", |b| {
+        b.iter(|| {
+            for _ in 0..NUM_VERIFICATIONS {
+                black_box(verify_proof_prepared(&pvk, &proof, &public_inputs).unwrap());
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_prepared_verify);
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+//! Benchmarks for the trusted setup
+//!
+//! Run with: cargo bench --package groth16 --bench setup
+//!
+//! Compares the default setup (plain scalar multiplication of the
+//! generators) against fixed-base precomputed tables, for circuits of
+//! increasing size. The tables have a fixed build cost, so the speedup
+//! only shows once the circuit has enough variables.
+
+use ark_bn254::Fr;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use groth16::{trusted_setup_with_options, SetupOptions};
+use groth16_math::fields::FieldWrapper;
+use groth16_math::polynomial::Polynomial;
+use groth16_qap::r1cs_to_qap;
+use groth16_r1cs::constraint::R1CSConstraint;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+type Qap = (
+    Vec<Polynomial<Fr>>,
+    Vec<Polynomial<Fr>>,
+    Vec<Polynomial<Fr>>,
+);
+
+/// Builds a chain of squarings x₀² = x₁, x₁² = x₂, ... with `size` constraints
+///
+/// Witness layout: [1, x₀, x₁, ..., x_size]
+fn squaring_chain(size: usize) -> Qap {
+    let constraints: Vec<_> = (1..=size)
+        .map(|i| {
+            let mut constraint = R1CSConstraint::<Fr>::new();
+            constraint.add_a_variable(i, FieldWrapper::<Fr>::from(1u64));
+            constraint.add_b_variable(i, FieldWrapper::<Fr>::from(1u64));
+            constraint.add_c_variable(i + 1, FieldWrapper::<Fr>::from(1u64));
+            constraint
+        })
+        .collect();
+
+    r1cs_to_qap(&constraints, size + 2).unwrap()
+}
+
+fn bench_setup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trusted_setup");
+    group.sample_size(10);
+
+    let default_options = SetupOptions::default();
+    let table_options = SetupOptions {
+        use_precomputed_tables: true,
+        ..SetupOptions::default()
+    };
+
+    for size in [8, 32, 64].iter() {
+        let (a_polys, b_polys, c_polys) = squaring_chain(*size);
+
+        for (name, options) in [("default", default_options), ("tables", table_options)] {
+            group.bench_with_input(BenchmarkId::new(name, size), size, |b, _| {
+                b.iter(|| {
+                    let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+                    black_box(
+                        trusted_setup_with_options(
+                            &a_polys, &b_polys, &c_polys, *size, 1, &options, &mut rng,
+                        )
+                        .unwrap(),
+                    );
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_setup);
+criterion_main!(benches);
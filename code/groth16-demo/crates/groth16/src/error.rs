@@ -24,6 +24,135 @@ pub enum Groth16Error {
     #[error("Division error: {0}")]
     DivisionError(String),
 
+    #[error("IC length mismatch: expected {expected}, got {actual}")]
+    IcLengthMismatch { expected: usize, actual: usize },
+
+    #[error("Invalid proving key: {query} has {actual} elements, expected {expected}")]
+    InvalidProvingKey {
+        query: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Invalid public input index {index}: expected 1..={num_inputs}")]
+    InvalidPublicInputIndex { index: usize, num_inputs: usize },
+
+    #[error("Invalid setup options: {0}")]
+    InvalidSetupOptions(String),
+
+    #[error("Setup RNG drew zero for {0}, which must be invertible")]
+    ZeroSetupSecret(&'static str),
+
+    #[error("Circuit too large: QAP degree {degree} exceeds the maximum of {max}")]
+    CircuitTooLarge { degree: usize, max: usize },
+
+    #[error(
+        "Constraint count {num_constraints} is too small: the QAP polynomials need at least {min}"
+    )]
+    ConstraintCountMismatch { num_constraints: usize, min: usize },
+
     #[error("QAP error: {0}")]
-    QapError(String),
+    Qap(#[from] groth16_qap::QapError),
+}
+
+impl Groth16Error {
+    /// Returns a short, user-facing suggestion for fixing this error.
+    ///
+    /// The `Display` message says what went wrong; the hint says what to
+    /// try next, so a CLI can print both.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Groth16Error::MismatchedPolynomials(..) => {
+                "Ensure the A, B and C polynomials come from the same r1cs_to_qap call with consistent constraints."
+            }
+            Groth16Error::EmptyPolynomials => {
+                "The circuit has no QAP polynomials; check that it defines at least one constraint and variable."
+            }
+            Groth16Error::InvalidInputs(_) => {
+                "num_inputs must not exceed the number of variables minus one (the constant 1 is not a public input)."
+            }
+            Groth16Error::EvaluationError(_) => {
+                "Check that every polynomial and witness value belongs to the scalar field of the chosen curve."
+            }
+            Groth16Error::SerializationError(_) => {
+                "The bytes are truncated, corrupted or from another format; regenerate them with the matching to_bytes."
+            }
+            Groth16Error::InvalidWitnessLength { .. } => {
+                "Pass the full witness [1, public..., private...] with one value per QAP variable."
+            }
+            Groth16Error::DivisionError(_) => {
+                "The witness likely does not satisfy the constraints; check each constraint with is_satisfied."
+            }
+            Groth16Error::IcLengthMismatch { .. } => {
+                "Use the verification key produced by the same setup, and pass exactly num_inputs public inputs."
+            }
+            Groth16Error::InvalidProvingKey { .. } => {
+                "The proving key is truncated or belongs to another circuit; rerun the setup for these QAP polynomials."
+            }
+            Groth16Error::InvalidPublicInputIndex { .. } => {
+                "Label public inputs by witness index starting at 1; index 0 is the constant 1."
+            }
+            Groth16Error::InvalidSetupOptions(_) => {
+                "Choose window_bits between 1 and 16, or use SetupOptions::default()."
+            }
+            Groth16Error::ZeroSetupSecret(_) => {
+                "The SetupRng must return uniformly random field elements; check it is seeded and not stuck at zero."
+            }
+            Groth16Error::CircuitTooLarge { .. } => {
+                "Split the circuit or raise SetupOptions::max_degree if the memory is available."
+            }
+            Groth16Error::ConstraintCountMismatch { .. } => {
+                "Pass the number of R1CS constraints the QAP polynomials were built from."
+            }
+            Groth16Error::Qap(_) => {
+                "The R1CS could not be turned into a QAP; check the constraint system is non-empty and well-formed."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_variant_has_hint() {
+        let errors = [
+            Groth16Error::MismatchedPolynomials(1, 2, 3),
+            Groth16Error::EmptyPolynomials,
+            Groth16Error::InvalidInputs(5),
+            Groth16Error::EvaluationError("eval".to_string()),
+            Groth16Error::SerializationError("bytes".to_string()),
+            Groth16Error::InvalidWitnessLength {
+                expected: 4,
+                actual: 3,
+            },
+            Groth16Error::DivisionError("div".to_string()),
+            Groth16Error::IcLengthMismatch {
+                expected: 2,
+                actual: 1,
+            },
+            Groth16Error::InvalidProvingKey {
+                query: "h_query",
+                expected: 2,
+                actual: 1,
+            },
+            Groth16Error::InvalidPublicInputIndex {
+                index: 0,
+                num_inputs: 1,
+            },
+            Groth16Error::InvalidSetupOptions("window".to_string()),
+            Groth16Error::ZeroSetupSecret("γ"),
+            Groth16Error::CircuitTooLarge { degree: 8, max: 4 },
+            Groth16Error::ConstraintCountMismatch {
+                num_constraints: 1,
+                min: 3,
+            },
+            Groth16Error::Qap(groth16_qap::QapError::EmptyConstraints),
+        ];
+
+        for error in &errors {
+            assert!(!error.hint().is_empty(), "missing hint for {error:?}");
+        }
+    }
 }
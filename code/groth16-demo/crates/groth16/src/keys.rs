@@ -1,6 +1,10 @@
-use ark_bn254::{G1Affine, G2Affine};
+use crate::error::Groth16Error;
+use crate::setup::qap_degree;
+use ark_bn254::Bn254;
+use ark_ec::pairing::{Pairing, PairingOutput};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
 
 /// Helper to serialize a single arkworks type to bytes
 fn serialize_to_bytes<T>(value: &T) -> Vec<u8>
@@ -38,11 +42,10 @@ where
     T: CanonicalDeserialize,
 {
     let mut values = Vec::new();
-    let mut offset = 0;
-    while offset < bytes.len() {
-        let remaining = &bytes[offset..];
-        let value = T::deserialize_compressed(remaining).unwrap();
-        offset += bytes.len() - remaining.len();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        // Deserializing through `&mut &[u8]` advances the slice past the value
+        let value = T::deserialize_compressed(&mut remaining).unwrap();
         values.push(value);
     }
     values
@@ -52,37 +55,40 @@ where
 ///
 /// The proving key contains all encrypted elements needed to generate proofs.
 /// It must be kept secret by the prover (though in Groth16, it's public knowledge).
+///
+/// Generic over the pairing curve `E`, BN254 by default.
 #[derive(Clone, Debug)]
-pub struct ProvingKey {
+pub struct ProvingKey<E: Pairing = Bn254> {
     /// α·G₁ (used in proof A component)
-    pub alpha_g1: G1Affine,
+    pub alpha_g1: E::G1Affine,
 
     /// β·G₁ (used in proof A component)
-    pub beta_g1: G1Affine,
+    pub beta_g1: E::G1Affine,
 
     /// β·G₂ (used in verification)
-    pub beta_g2: G2Affine,
+    pub beta_g2: E::G2Affine,
 
     /// δ·G₁ (used in proof C component)
-    pub delta_g1: G1Affine,
+    pub delta_g1: E::G1Affine,
 
     /// δ·G₂ (used in verification)
-    pub delta_g2: G2Affine,
+    pub delta_g2: E::G2Affine,
 
-    /// Encrypted A-polynomials: [α·Aᵢ(τ)·G₁] for i=0..m
-    pub a_query: Vec<G1Affine>,
+    /// Encrypted A-polynomials: [Aᵢ(τ)·G₁] for i=0..m
+    pub a_query: Vec<E::G1Affine>,
 
-    /// Encrypted B-polynomials in G1: [β·Bᵢ(τ)·G₁] for i=0..m
-    pub b_g1_query: Vec<G1Affine>,
+    /// Encrypted B-polynomials in G1: [Bᵢ(τ)·G₁] for i=0..m
+    pub b_g1_query: Vec<E::G1Affine>,
 
-    /// Encrypted B-polynomials in G2: [β·Bᵢ(τ)·G₂] for i=0..m
-    pub b_g2_query: Vec<G2Affine>,
+    /// Encrypted B-polynomials in G2: [Bᵢ(τ)·G₂] for i=0..m
+    pub b_g2_query: Vec<E::G2Affine>,
 
-    /// Encrypted C-polynomials: [β·Cᵢ(τ)·G₁] for i=0..m
-    pub c_query: Vec<G1Affine>,
+    /// Private variable terms: [(β·Aᵢ(τ) + α·Bᵢ(τ) + Cᵢ(τ))/δ·G₁] for i=0..m,
+    /// with the identity at the constant and the public inputs
+    pub c_query: Vec<E::G1Affine>,
 
-    /// Encrypted division polynomials: [Hᵢ(τ)·G₁] for i=0..n-2
-    pub h_query: Vec<G1Affine>,
+    /// Division polynomial basis: [τⁱ·t(τ)/δ·G₁] for i=0..n-2, where n is the QAP degree
+    pub h_query: Vec<E::G1Affine>,
 }
 
 /// Serializable representation of ProvingKey
@@ -110,8 +116,8 @@ struct ProvingKeyRepr {
     h_query: Vec<u8>,
 }
 
-impl From<&ProvingKey> for ProvingKeyRepr {
-    fn from(pk: &ProvingKey) -> Self {
+impl<E: Pairing> From<&ProvingKey<E>> for ProvingKeyRepr {
+    fn from(pk: &ProvingKey<E>) -> Self {
         ProvingKeyRepr {
             alpha_g1: serialize_to_bytes(&pk.alpha_g1),
             beta_g1: serialize_to_bytes(&pk.beta_g1),
@@ -127,7 +133,7 @@ impl From<&ProvingKey> for ProvingKeyRepr {
     }
 }
 
-impl From<&ProvingKeyRepr> for ProvingKey {
+impl<E: Pairing> From<&ProvingKeyRepr> for ProvingKey<E> {
     fn from(repr: &ProvingKeyRepr) -> Self {
         ProvingKey {
             alpha_g1: deserialize_from_bytes(&repr.alpha_g1),
@@ -144,7 +150,7 @@ impl From<&ProvingKeyRepr> for ProvingKey {
     }
 }
 
-impl Serialize for ProvingKey {
+impl<E: Pairing> Serialize for ProvingKey<E> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -153,7 +159,7 @@ impl Serialize for ProvingKey {
     }
 }
 
-impl<'de> Deserialize<'de> for ProvingKey {
+impl<'de, E: Pairing> Deserialize<'de> for ProvingKey<E> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -163,28 +169,72 @@ impl<'de> Deserialize<'de> for ProvingKey {
     }
 }
 
+impl<E: Pairing> ProvingKey<E> {
+    /// Checks that every query has the length the circuit requires.
+    ///
+    /// The prover indexes the queries by witness position, so a truncated
+    /// key would either panic or, for `h_query`, silently drop terms and
+    /// yield a proof that can never verify. `a_query`, `b_g1_query`,
+    /// `b_g2_query` and `c_query` need one point per variable; `h_query`
+    /// needs `qap_degree(num_constraints) - 1` points, see
+    /// [`qap_degree`](crate::setup::qap_degree).
+    ///
+    /// # Errors
+    /// * `Groth16Error::InvalidProvingKey` - Naming the first query with the wrong length
+    pub fn validate(
+        &self,
+        expected_vars: usize,
+        num_constraints: usize,
+    ) -> Result<(), Groth16Error> {
+        let lengths = [
+            ("a_query", self.a_query.len(), expected_vars),
+            ("b_g1_query", self.b_g1_query.len(), expected_vars),
+            ("b_g2_query", self.b_g2_query.len(), expected_vars),
+            ("c_query", self.c_query.len(), expected_vars),
+            (
+                "h_query",
+                self.h_query.len(),
+                qap_degree(num_constraints) - 1,
+            ),
+        ];
+
+        for (query, actual, expected) in lengths {
+            if actual != expected {
+                return Err(Groth16Error::InvalidProvingKey {
+                    query,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Verification key for Groth16
 ///
 /// The verification key contains the public elements needed to verify proofs.
 /// It can be shared publicly.
+///
+/// Generic over the pairing curve `E`, BN254 by default.
 #[derive(Clone, Debug)]
-pub struct VerificationKey {
+pub struct VerificationKey<E: Pairing = Bn254> {
     /// α·G₁ (part of verification equation)
-    pub alpha_g1: G1Affine,
+    pub alpha_g1: E::G1Affine,
 
     /// β·G₂ (part of verification equation)
-    pub beta_g2: G2Affine,
+    pub beta_g2: E::G2Affine,
 
     /// γ·G₂ (base for public input encryption)
-    pub gamma_g2: G2Affine,
+    pub gamma_g2: E::G2Affine,
 
     /// δ·G₂ (base for proof C encryption)
-    pub delta_g2: G2Affine,
+    pub delta_g2: E::G2Affine,
 
-    /// Public input encryption: [β·G₁, β·U₁(τ)·G₁ + α·H₁(τ)·G₁, ...]
-    /// The first element IC[0] = β·G₁ is for the constant 1,
+    /// Public input encryption: [(β·Aᵢ(τ) + α·Bᵢ(τ) + Cᵢ(τ))/γ·G₁]
+    /// The first element IC[0] is for the constant 1,
     /// followed by elements for each public input
-    pub ic: Vec<G1Affine>,
+    pub ic: Vec<E::G1Affine>,
 }
 
 /// Serializable representation of VerificationKey
@@ -202,8 +252,8 @@ struct VerificationKeyRepr {
     ic: Vec<u8>,
 }
 
-impl From<&VerificationKey> for VerificationKeyRepr {
-    fn from(vk: &VerificationKey) -> Self {
+impl<E: Pairing> From<&VerificationKey<E>> for VerificationKeyRepr {
+    fn from(vk: &VerificationKey<E>) -> Self {
         VerificationKeyRepr {
             alpha_g1: serialize_to_bytes(&vk.alpha_g1),
             beta_g2: serialize_to_bytes(&vk.beta_g2),
@@ -214,7 +264,7 @@ impl From<&VerificationKey> for VerificationKeyRepr {
     }
 }
 
-impl From<&VerificationKeyRepr> for VerificationKey {
+impl<E: Pairing> From<&VerificationKeyRepr> for VerificationKey<E> {
     fn from(repr: &VerificationKeyRepr) -> Self {
         VerificationKey {
             alpha_g1: deserialize_from_bytes(&repr.alpha_g1),
@@ -226,7 +276,61 @@ impl From<&VerificationKeyRepr> for VerificationKey {
     }
 }
 
-impl Serialize for VerificationKey {
+impl<E: Pairing> VerificationKey<E> {
+    /// Returns a stable 32-byte fingerprint of this verification key.
+    ///
+    /// The fingerprint is the SHA-256 hash of the compressed encodings of
+    /// α·G₁, β·G₂, γ·G₂, δ·G₂ and every IC element, in that order, with the
+    /// IC length prefixed. It is unchanged by serialization round-trips and
+    /// can be used to bind proofs and other artifacts to the key they were
+    /// generated for.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"groth16-demo/vk-fingerprint/v1");
+        hasher.update(serialize_to_bytes(&self.alpha_g1));
+        hasher.update(serialize_to_bytes(&self.beta_g2));
+        hasher.update(serialize_to_bytes(&self.gamma_g2));
+        hasher.update(serialize_to_bytes(&self.delta_g2));
+        hasher.update((self.ic.len() as u64).to_le_bytes());
+        hasher.update(serialize_vec_to_bytes(&self.ic));
+        hasher.finalize().into()
+    }
+
+    /// Precomputes the key-dependent parts of verification.
+    ///
+    /// e(α, β) is the same for every proof, and γ and δ are always the G₂
+    /// side of a pairing, so their Miller-loop line coefficients can be
+    /// computed once. Use with [`verify_proof_prepared`](crate::verify::verify_proof_prepared)
+    /// when checking many proofs against one key.
+    pub fn prepare(self) -> PreparedVerificationKey<E> {
+        PreparedVerificationKey {
+            alpha_beta: E::pairing(self.alpha_g1, self.beta_g2),
+            gamma_g2_prepared: self.gamma_g2.into(),
+            delta_g2_prepared: self.delta_g2.into(),
+            vk: self,
+        }
+    }
+}
+
+/// A verification key with its fixed pairing work done up front.
+///
+/// Built by [`VerificationKey::prepare`].
+#[derive(Clone, Debug)]
+pub struct PreparedVerificationKey<E: Pairing = Bn254> {
+    /// The key this was prepared from
+    pub vk: VerificationKey<E>,
+
+    /// e(α, β)
+    pub alpha_beta: PairingOutput<E>,
+
+    /// γ·G₂, prepared for the Miller loop
+    pub gamma_g2_prepared: E::G2Prepared,
+
+    /// δ·G₂, prepared for the Miller loop
+    pub delta_g2_prepared: E::G2Prepared,
+}
+
+impl<E: Pairing> Serialize for VerificationKey<E> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -235,7 +339,7 @@ impl Serialize for VerificationKey {
     }
 }
 
-impl<'de> Deserialize<'de> for VerificationKey {
+impl<'de, E: Pairing> Deserialize<'de> for VerificationKey<E> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -244,3 +348,48 @@ impl<'de> Deserialize<'de> for VerificationKey {
         Ok(VerificationKey::from(&repr))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::trusted_setup_test;
+    use ark_bn254::Fr;
+    use groth16_math::fields::FieldWrapper;
+    use groth16_qap::r1cs_to_qap;
+    use groth16_r1cs::constraint::R1CSConstraint;
+
+    fn setup_vk(seed: [u8; 32]) -> VerificationKey {
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+
+        let constraints = vec![c1.clone(), c1];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+        let (_pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        vk
+    }
+
+    #[test]
+    fn test_fingerprint_same_seed() {
+        let vk1 = setup_vk([42u8; 32]);
+        let vk2 = setup_vk([42u8; 32]);
+        assert_eq!(vk1.fingerprint(), vk2.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_different_seed() {
+        let vk1 = setup_vk([42u8; 32]);
+        let vk2 = setup_vk([7u8; 32]);
+        assert_ne!(vk1.fingerprint(), vk2.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_stable_across_serialization() {
+        let vk = setup_vk([42u8; 32]);
+        let bytes = bincode::serialize(&vk).unwrap();
+        let restored: VerificationKey = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(vk.fingerprint(), restored.fingerprint());
+    }
+}
@@ -4,15 +4,41 @@
 //! - Trusted setup (generating pk and vk)
 //! - Proof generation
 //! - Proof verification
+//!
+//! With the `tracing` feature, proving and verification emit `tracing` spans
+//! for each phase, recording the circuit size as span fields.
+
+/// Runs `$body` inside a `tracing` span named `$name`, with the given fields.
+///
+/// Without the `tracing` feature this is just `$body`, and the field
+/// expressions are not evaluated.
+macro_rules! traced {
+    ($name:literal, { $($field:ident = $value:expr),* $(,)? }, $body:block) => {{
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($name, $($field = $value),*).entered();
+        $body
+    }};
+    ($name:literal, $body:block) => {
+        traced!($name, {}, $body)
+    };
+}
 
 pub mod error;
 pub mod keys;
 pub mod prove;
+pub mod public_input;
 pub mod setup;
 pub mod verify;
 
 pub use error::Groth16Error;
-pub use keys::{ProvingKey, VerificationKey};
-pub use prove::{generate_proof, generate_proof_test, Proof};
-pub use setup::{trusted_setup, trusted_setup_test};
-pub use verify::{batch_verify, verify_proof};
+pub use keys::{PreparedVerificationKey, ProvingKey, VerificationKey};
+pub use prove::{generate_proof, generate_proof_test, generate_proof_unblinded, Proof};
+pub use public_input::PublicInput;
+pub use setup::{
+    qap_degree, trusted_setup, trusted_setup_from_r1cs, trusted_setup_test,
+    trusted_setup_with_options, KeyPair, SetupOptions, SetupRng, MAX_DEGREE,
+};
+pub use verify::{
+    batch_verify, verification_cost, verify_proof, verify_proof_detailed, verify_proof_labeled,
+    verify_proof_prepared, ProofWithInputs, RejectReason, VerificationCost, VerificationResult,
+};
@@ -1,24 +1,171 @@
 use crate::error::Groth16Error;
-use crate::keys::ProvingKey;
-use ark_bn254::{Fr, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2};
+use crate::keys::{ProvingKey, VerificationKey};
+use crate::setup::interpolated_degree;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
 use ark_ec::CurveGroup;
 use ark_ff::{Field, PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use groth16_math::fields::FieldWrapper;
+use groth16_math::pairing::{batch_to_affine, msm, PairingCurve};
 use groth16_math::polynomial::Polynomial;
 use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::fmt;
 
 /// Groth16 proof
 ///
 /// A Groth16 proof consists of three group elements that demonstrate
 /// knowledge of a valid witness without revealing it.
+///
+/// Generic over the pairing curve `E`, BN254 by default.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Proof {
+pub struct Proof<E: Pairing = Bn254> {
     /// Proof component A in G₁
-    pub a: G1Affine,
+    pub a: E::G1Affine,
     /// Proof component B in G₂
-    pub b: G2Affine,
+    pub b: E::G2Affine,
     /// Proof component C in G₁
-    pub c: G1Affine,
+    pub c: E::G1Affine,
+}
+
+impl<E: Pairing> Proof<E> {
+    /// Returns the size of the proof in bytes using compressed point encoding.
+    ///
+    /// A compressed BN254 proof is two G₁ points (32 bytes each) and one
+    /// G₂ point (64 bytes), i.e. 128 bytes regardless of circuit size
+    /// (192 bytes on BLS12-381).
+    pub fn size_bytes(&self) -> usize {
+        self.a.compressed_size() + self.b.compressed_size() + self.c.compressed_size()
+    }
+
+    /// Serializes the proof as compressed A || B || C (128 bytes on BN254).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.size_bytes());
+        self.a
+            .serialize_compressed(&mut bytes)
+            .expect("Serialization to Vec should not fail");
+        self.b
+            .serialize_compressed(&mut bytes)
+            .expect("Serialization to Vec should not fail");
+        self.c
+            .serialize_compressed(&mut bytes)
+            .expect("Serialization to Vec should not fail");
+        bytes
+    }
+
+    /// Returns the SHA-256 hash of [`to_bytes`](Self::to_bytes).
+    ///
+    /// Equal proofs have equal ids, so this works as a correlation key in
+    /// logs. Note that [`rerandomize`](Self::rerandomize) changes the id.
+    pub fn id(&self) -> [u8; 32] {
+        Sha256::digest(self.to_bytes()).into()
+    }
+
+    /// Returns a fresh-looking proof of the same statement.
+    ///
+    /// Anyone holding the verification key can re-randomize a proof, so the
+    /// same credential can be shown twice without the two showings being
+    /// linkable. With random r₁ ≠ 0 and r₂:
+    ///
+    /// ```text
+    /// A' = r₁⁻¹·A,  B' = r₁·B + r₁·r₂·δ,  C' = C + r₂·A
+    /// ```
+    ///
+    /// Then e(A', B') = e(A, B) · e(A, δ)^r₂ and e(C', δ) = e(C, δ) · e(A, δ)^r₂,
+    /// so the verification equation holds for the new proof exactly when it
+    /// held for the original.
+    pub fn rerandomize<R: Rng + ?Sized>(&self, vk: &VerificationKey<E>, rng: &mut R) -> Self {
+        let r1 = loop {
+            let r1 = E::ScalarField::rand(rng);
+            if !r1.is_zero() {
+                break r1;
+            }
+        };
+        let r2 = E::ScalarField::rand(rng);
+        let r1_inv = r1.inverse().expect("r1 is non-zero");
+
+        let a = E::G1::from(self.a);
+        let b = E::G2::from(self.b) * r1 + E::G2::from(vk.delta_g2) * (r1 * r2);
+        let [a_new, c_new]: [E::G1Affine; 2] =
+            batch_to_affine(&[a * r1_inv, E::G1::from(self.c) + a * r2])
+                .try_into()
+                .expect("Two points in, two points out");
+
+        Proof {
+            a: a_new,
+            b: b.into_affine(),
+            c: c_new,
+        }
+    }
+
+    /// Deserializes a proof produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Proof bytes are untrusted input, so every point is checked to be on
+    /// the curve and in the prime-order subgroup before the proof is
+    /// returned. A point outside the subgroup could otherwise be fed to the
+    /// pairing and yield a wrong verification result (a subgroup attack).
+    ///
+    /// # Errors
+    /// * `Groth16Error::SerializationError` - If the bytes are malformed,
+    ///   have trailing data, or contain an invalid point
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Groth16Error> {
+        let mut reader = bytes;
+        let a: E::G1Affine = read_checked_point(&mut reader, "A")?;
+        let b: E::G2Affine = read_checked_point(&mut reader, "B")?;
+        let c: E::G1Affine = read_checked_point(&mut reader, "C")?;
+
+        if !reader.is_empty() {
+            return Err(Groth16Error::SerializationError(format!(
+                "{} trailing bytes after proof",
+                reader.len()
+            )));
+        }
+
+        Ok(Self { a, b, c })
+    }
+}
+
+/// Reads one compressed point and checks curve and subgroup membership
+fn read_checked_point<P: CanonicalDeserialize>(
+    reader: &mut &[u8],
+    name: &str,
+) -> Result<P, Groth16Error> {
+    let point = P::deserialize_with_mode(&mut *reader, Compress::Yes, Validate::No)
+        .map_err(|e| Groth16Error::SerializationError(format!("proof point {}: {}", name, e)))?;
+
+    // For curve points, `check` tests curve and prime-order subgroup membership
+    if point.check().is_err() {
+        return Err(Groth16Error::SerializationError(format!(
+            "proof point {} is not on the curve or not in the prime-order subgroup",
+            name
+        )));
+    }
+
+    Ok(point)
+}
+
+impl<E: Pairing> fmt::Display for Proof<E> {
+    /// Prints the first 8 bytes of each compressed point in hex, e.g.
+    /// `Proof(a=ab12…, b=cd34…, c=ef56…)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Proof(a={}…, b={}…, c={}…)",
+            hex_prefix(&self.a),
+            hex_prefix(&self.b),
+            hex_prefix(&self.c)
+        )
+    }
+}
+
+/// Hex of the first 8 bytes of a point's compressed encoding
+fn hex_prefix<P: CanonicalSerialize>(point: &P) -> String {
+    let mut bytes = Vec::new();
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("Serialization to Vec should not fail");
+    bytes[..8].iter().map(|b| format!("{b:02x}")).collect()
 }
 
 /// Generates a Groth16 zero-knowledge proof.
@@ -34,12 +181,16 @@ pub struct Proof {
 ///
 /// # Returns
 /// * `Ok(Proof)` - The zero-knowledge proof
-/// * `Err(Groth16Error)` - Error if proof generation fails
+/// * `Err(Groth16Error)` - Error if proof generation fails, including
+///   `InvalidProvingKey` when `pk` does not match the polynomials
+///   (see [`ProvingKey::validate`])
 ///
 /// # Algorithm
 /// 1. Evaluate QAP polynomials at the witness point:
 ///    - A_witness = Σⱼ witness[j]·Aⱼ(τ)  (using pk.a_query)
 ///    - B_witness = Σⱼ witness[j]·Bⱼ(τ)  (using pk.b_g1_query, pk.b_g2_query)
+///    - C_private = Σ witness[j]·(β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ))/δ over private j
+///      (using pk.c_query)
 /// 2. Compute the division polynomial H(x):
 ///    - First compute p(x) = A_witness(x)·B_witness(x) - C_witness(x)
 ///    - Then H(x) = p(x) / t(x) where t(x) is the target polynomial
@@ -47,7 +198,7 @@ pub struct Proof {
 /// 4. Compute proof components:
 ///    - A = α·G₁ + A_witness + r·δ·G₁
 ///    - B = β·G₂ + B_witness + s·δ·G₂
-///    - C = C_witness + H + additional terms for r and s
+///    - C = C_private + H(τ)·t(τ)/δ + s·A + r·B - r·s·δ  (using pk.h_query)
 ///
 /// # Security
 /// The random values r and s ensure zero-knowledge: different witnesses
@@ -64,7 +215,7 @@ pub struct Proof {
 ///
 /// // Get QAP polynomials and proving key
 /// let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4)?;
-/// let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, 1, &mut rng)?;
+/// let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &mut rng)?;
 ///
 /// // Generate proof
 /// let proof = generate_proof(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &mut rng)?;
@@ -72,15 +223,60 @@ pub struct Proof {
 /// // Proof can now be verified against public inputs (c=12)
 /// # Ok::<(), Groth16Error>(())
 /// ```
-pub fn generate_proof<R: Rng + ?Sized>(
-    pk: &ProvingKey,
-    witness: &[FieldWrapper<Fr>],
-    a_polys: &[Polynomial<Fr>],
-    b_polys: &[Polynomial<Fr>],
-    c_polys: &[Polynomial<Fr>],
+pub fn generate_proof<E: PairingCurve, R: Rng + ?Sized>(
+    pk: &ProvingKey<E>,
+    witness: &[FieldWrapper<E::ScalarField>],
+    a_polys: &[Polynomial<E::ScalarField>],
+    b_polys: &[Polynomial<E::ScalarField>],
+    c_polys: &[Polynomial<E::ScalarField>],
     _public_inputs: usize,
     rng: &mut R,
-) -> Result<Proof, Groth16Error> {
+) -> Result<Proof<E>, Groth16Error> {
+    // Random blinding factors make the proof zero-knowledge
+    let r = E::ScalarField::rand(rng);
+    let s = E::ScalarField::rand(rng);
+
+    prove_with_blinding(pk, witness, a_polys, b_polys, c_polys, r, s)
+}
+
+/// Generates a Groth16 proof with the blinding factors fixed to r = s = 0.
+///
+/// # Warning: NOT zero-knowledge
+/// Without blinding, A and B are deterministic functions of the witness and
+/// the proving key, so the proof can leak information about private inputs.
+/// This exists only for debugging and golden-file tests, where byte-identical
+/// proofs across runs are needed. Use [`generate_proof`] for anything else.
+///
+/// Arguments and errors are the same as [`generate_proof`].
+pub fn generate_proof_unblinded<E: PairingCurve>(
+    pk: &ProvingKey<E>,
+    witness: &[FieldWrapper<E::ScalarField>],
+    a_polys: &[Polynomial<E::ScalarField>],
+    b_polys: &[Polynomial<E::ScalarField>],
+    c_polys: &[Polynomial<E::ScalarField>],
+    _public_inputs: usize,
+) -> Result<Proof<E>, Groth16Error> {
+    prove_with_blinding(
+        pk,
+        witness,
+        a_polys,
+        b_polys,
+        c_polys,
+        E::ScalarField::zero(),
+        E::ScalarField::zero(),
+    )
+}
+
+/// Builds the proof components for the given blinding factors r and s
+fn prove_with_blinding<E: PairingCurve>(
+    pk: &ProvingKey<E>,
+    witness: &[FieldWrapper<E::ScalarField>],
+    a_polys: &[Polynomial<E::ScalarField>],
+    b_polys: &[Polynomial<E::ScalarField>],
+    c_polys: &[Polynomial<E::ScalarField>],
+    r: E::ScalarField,
+    s: E::ScalarField,
+) -> Result<Proof<E>, Groth16Error> {
     // Validate inputs
     if witness.is_empty() {
         return Err(Groth16Error::InvalidWitnessLength {
@@ -96,134 +292,159 @@ pub fn generate_proof<R: Rng + ?Sized>(
         });
     }
 
-    // Step 1: Compute A_base = Σⱼ witness[j]·Aⱼ(τ) (unblinded, without α)
-    // pk.a_query contains [α·Aⱼ(τ)] so we need to subtract α
-    let mut a_witness_blinded = G1::zero();
-    for (j, w) in witness.iter().enumerate() {
-        let w_fr = w.value;
-        let g1_point = G1::from(pk.a_query[j]);
-        a_witness_blinded += g1_point * w_fr;
-    }
-    // Extract α contribution: α·Σ witness[j] where j=0 is the constant 1
-    let alpha_sum = witness[0].value;
-    let a_alpha_part = G1::from(pk.alpha_g1) * alpha_sum;
-    let a_base = a_witness_blinded - a_alpha_part;
-
-    // Step 2: Compute B_base = Σⱼ witness[j]·Bⱼ(τ) (unblinded, without β)
-    let mut b_witness_g1_blinded = G1::zero();
-    let mut b_witness_g2_blinded = G2::zero();
-
-    for (j, w) in witness.iter().enumerate() {
-        let w_fr = w.value;
-        let bg1_point = G1::from(pk.b_g1_query[j]);
-        let bg2_point = G2::from(pk.b_g2_query[j]);
-        b_witness_g1_blinded += bg1_point * w_fr;
-        b_witness_g2_blinded += bg2_point * w_fr;
-    }
-    // Extract β contribution: β·Σ witness[j]
-    let beta_sum = alpha_sum; // Same sum
-    let b_beta_part_g1 = G1::from(pk.beta_g1) * beta_sum;
-    let b_beta_part_g2 = G2::from(pk.beta_g2) * beta_sum;
-    let b_base_g1 = b_witness_g1_blinded - b_beta_part_g1;
-    let b_base_g2 = b_witness_g2_blinded - b_beta_part_g2;
-
-    // Step 3: Compute C_base = Σⱼ witness[j]·Cⱼ(τ) (already has β)
-    let mut c_base = G1::zero();
-    for (j, w) in witness.iter().enumerate() {
-        let w_fr = w.value;
-        let g1_point = G1::from(pk.c_query[j]);
-        c_base += g1_point * w_fr;
-    }
-
-    // Step 4: Generate random blinding factors
-    let r = Fr::rand(rng);
-    let s = Fr::rand(rng);
-
-    // Step 5: Compute proof component A
-    // A = α·G₁ + A_base + r·δ·G₁
-    let delta_g1 = G1::from(pk.delta_g1);
-    let a_g1 = G1::from(pk.alpha_g1) + a_base + delta_g1 * r;
-
-    // Step 6: Compute proof component B
-    // B = β·G₂ + B_base + s·δ·G₂
-    let delta_g2 = G2::from(pk.delta_g2);
-    let b_g2 = G2::from(pk.beta_g2) + b_base_g2 + delta_g2 * s;
-
-    // Step 7: Compute proof component C
-    // According to Groth16 paper:
-    // C = [β·A(x) + α·B(x) + C(x)]_x=τ + H(x)·Z(x)_x=τ + δ·r·s
-    //
-    // Where:
-    // - A_base = Σ witness[j]·Aⱼ(τ) (without α)
-    // - B_base = Σ witness[j]·Bⱼ(τ) (without β)
-    // - C_base = Σ witness[j]·Cⱼ(τ) (with β, but we need to subtract β for unblinded)
-    //
-    // The formula simplifies to:
-    // C = β·A_base + α·B_base + C_base_unblinded + H(τ) + r·s·δ
-    //   = (β·A_base + α·B_base + C_base - β·witness[0]) + H(τ) + r·s·δ
-    //
-    // But we also need to add the blinding terms with r and s:
-    // C = β·A_base·s + α·B_base·r + C_base + H(τ) + δ·r·s
-    //
-    // Where A_base and B_base are the unblinded versions
+    // The setup sized h_query from the constraint count, which the
+    // polynomials alone cannot tell. They must still fit in it, and a short
+    // query would otherwise be indexed out of bounds
+    let num_constraints =
+        (pk.h_query.len() + 1).max(interpolated_degree(a_polys, b_polys, c_polys));
+    pk.validate(a_polys.len(), num_constraints)?;
+
+    traced!("groth16::prove", {
+        num_variables = a_polys.len(),
+        num_constraints = num_constraints,
+    }, {
+        prove_validated(pk, witness, a_polys, b_polys, c_polys, r, s)
+    })
+}
 
-    // First compute the H polynomial
+/// The body of [`prove_with_blinding`], once the inputs are known to be consistent
+fn prove_validated<E: PairingCurve>(
+    pk: &ProvingKey<E>,
+    witness: &[FieldWrapper<E::ScalarField>],
+    a_polys: &[Polynomial<E::ScalarField>],
+    b_polys: &[Polynomial<E::ScalarField>],
+    c_polys: &[Polynomial<E::ScalarField>],
+    r: E::ScalarField,
+    s: E::ScalarField,
+) -> Result<Proof<E>, Groth16Error> {
+    let scalars: Vec<E::ScalarField> = witness.iter().map(|w| w.value).collect();
+    let (a_witness, b_witness_g1, b_witness_g2, c_private) = traced!("msm", {
+        // Step 1: Compute Σⱼ witness[j]·Aⱼ(τ) and Σⱼ witness[j]·Bⱼ(τ)
+        // Witness scalars are secret, so G₁ sums are constant-time unless the
+        // `parallel` feature opts into a faster variable-time MSM
+        let a_witness = witness_sum_g1::<E>(&pk.a_query, &scalars);
+        let b_witness_g1 = witness_sum_g1::<E>(&pk.b_g1_query, &scalars);
+        let b_witness_g2 = witness_sum_g2::<E>(&pk.b_g2_query, &scalars);
+
+        // Step 2: Compute Σⱼ witness[j]·(β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ))/δ over the
+        // private variables (c_query is the identity at the public ones)
+        let c_private = witness_sum_g1::<E>(&pk.c_query, &scalars);
+
+        (a_witness, b_witness_g1, b_witness_g2, c_private)
+    });
+
+    // Step 3: Blinding factors r and s are supplied by the caller
+
+    // Step 4: Compute proof component A
+    // A = α·G₁ + Σ witness[j]·Aⱼ(τ) + r·δ·G₁
+    let delta_g1 = E::G1::from(pk.delta_g1);
+    let a_g1 = E::G1::from(pk.alpha_g1) + a_witness + delta_g1 * r;
+
+    // Step 5: Compute proof component B, in G₂ for the proof and in G₁ for C
+    // B = β·G₂ + Σ witness[j]·Bⱼ(τ) + s·δ·G₂
+    let b_g1 = E::G1::from(pk.beta_g1) + b_witness_g1 + delta_g1 * s;
+    let b_g2 = E::G2::from(pk.beta_g2) + b_witness_g2 + E::G2::from(pk.delta_g2) * s;
+
+    // Step 6: Compute the division polynomial H(x) = p(x)/t(x)
     // Compute the witness polynomials A_w(x), B_w(x), C_w(x)
-    let a_w_poly = compute_witness_polynomial(a_polys, witness);
-    let b_w_poly = compute_witness_polynomial(b_polys, witness);
-    let c_w_poly = compute_witness_polynomial(c_polys, witness);
+    let diff_poly = traced!("witness_polynomials", {
+        let a_w_poly = compute_witness_polynomial(a_polys, witness);
+        let b_w_poly = compute_witness_polynomial(b_polys, witness);
+        let c_w_poly = compute_witness_polynomial(c_polys, witness);
 
-    // Compute p(x) = A_w(x)·B_w(x) - C_w(x)
-    let product_poly = a_w_poly.clone() * b_w_poly.clone();
-    let diff_poly = product_poly - c_w_poly;
+        // Compute p(x) = A_w(x)·B_w(x) - C_w(x)
+        let product_poly = a_w_poly * b_w_poly;
+        product_poly - c_w_poly
+    });
 
-    // Get target polynomial t(x)
-    let num_constraints = a_polys.len() - 2;
-    let target_poly = groth16_qap::target_polynomial::<Fr>(num_constraints);
+    // Get target polynomial t(x), vanishing on the QAP's interpolation points
+    // The setup sized h_query to hold degree - 1 points
+    let degree = pk.h_query.len() + 1;
+    let target_poly = groth16_qap::target_polynomial::<E::ScalarField>(degree);
 
     // Divide to get H(x)
-    let (h_poly, _remainder) =
-        divide_polynomials(&diff_poly, &target_poly).map_err(Groth16Error::DivisionError)?;
-
-    // Evaluate H at τ using h_query
-    let mut h_tau = G1::zero();
-    for (j, coeff) in h_poly.coeffs.iter().enumerate() {
-        if j < pk.h_query.len() {
-            let g1_point = G1::from(pk.h_query[j]);
-            h_tau += g1_point * coeff.value;
-        }
-    }
-
-    // Now compute C with the correct Groth16 formula:
-    // C = A_base·s + B_base·r + C_base + H(τ) + δ·r·s
-    // where A_base and B_base are unblinded (without α, β)
-    // This matches the Groth16 paper specification for the C component
-    let c_g1 = a_base * s + b_base_g1 * r + c_base + h_tau + delta_g1 * (r * s);
+    let (h_poly, _remainder) = traced!("division", {
+        divide_polynomials(&diff_poly, &target_poly).map_err(Groth16Error::DivisionError)?
+    });
+
+    // Evaluate H(τ)·t(τ)/δ using h_query
+    // deg p ≤ 2·degree - 2, so H has at most h_query.len() = degree - 1 coefficients
+    let h_tau = traced!("h_msm", {
+        let mut h_coeffs: Vec<E::ScalarField> = h_poly.coeffs.iter().map(|c| c.value).collect();
+        h_coeffs.resize(pk.h_query.len(), E::ScalarField::zero());
+        witness_sum_g1::<E>(&pk.h_query, &h_coeffs)
+    });
 
-    // Convert to affine
+    // Step 7: Compute proof component C
+    // C = Σprivate + H(τ)·t(τ)/δ + s·A + r·B - r·s·δ
+    //
+    // Expanding A·B shows that e(A, B) = e(α, β) · e(Σpublic·IC, γ) · e(C, δ)
+    // holds exactly when A_w(τ)·B_w(τ) - C_w(τ) = H(τ)·t(τ)
+    let c_g1 = c_private + h_tau + a_g1 * s + b_g1 * r - delta_g1 * (r * s);
+
+    // Convert to affine, sharing one inversion between the two G1 points
+    let [a, c]: [E::G1Affine; 2] = batch_to_affine(&[a_g1, c_g1])
+        .try_into()
+        .expect("Two points in, two points out");
     let proof = Proof {
-        a: a_g1.into_affine(),
+        a,
         b: b_g2.into_affine(),
-        c: c_g1.into_affine(),
+        c,
     };
 
     Ok(proof)
 }
 
+/// Computes Σⱼ scalars[j]·bases[j] in G₁ for secret witness-derived scalars.
+///
+/// By default every term goes through the curve's constant-time
+/// [`PairingCurve::ct_g1_mul`]. The `parallel` feature switches to a
+/// multi-threaded Pippenger [`msm`], which is much faster but whose running
+/// time depends on the witness.
+fn witness_sum_g1<E: PairingCurve>(bases: &[E::G1Affine], scalars: &[E::ScalarField]) -> E::G1 {
+    if cfg!(feature = "parallel") {
+        msm(bases, scalars)
+    } else {
+        assert_eq!(bases.len(), scalars.len(), "one scalar per query point");
+        bases
+            .iter()
+            .zip(scalars)
+            .fold(E::G1::zero(), |acc, (base, scalar)| {
+                acc + E::ct_g1_mul(*base, *scalar)
+            })
+    }
+}
+
+/// Computes Σⱼ scalars[j]·bases[j] in G₂.
+///
+/// There is no constant-time G₂ multiplication, so the default path is a
+/// plain scalar-multiplication sum; `parallel` uses [`msm`] as for G₁.
+fn witness_sum_g2<E: PairingCurve>(bases: &[E::G2Affine], scalars: &[E::ScalarField]) -> E::G2 {
+    if cfg!(feature = "parallel") {
+        msm(bases, scalars)
+    } else {
+        assert_eq!(bases.len(), scalars.len(), "one scalar per query point");
+        bases
+            .iter()
+            .zip(scalars)
+            .fold(E::G2::zero(), |acc, (base, scalar)| acc + *base * scalar)
+    }
+}
+
 /// Generates a proof using a deterministic RNG for testing.
 ///
 /// This version uses a fixed seed instead of random entropy, making it
 /// reproducible across runs. Useful for testing but MUST NOT be used
 /// in production.
-pub fn generate_proof_test(
-    pk: &ProvingKey,
-    witness: &[FieldWrapper<Fr>],
-    a_polys: &[Polynomial<Fr>],
-    b_polys: &[Polynomial<Fr>],
-    c_polys: &[Polynomial<Fr>],
+pub fn generate_proof_test<E: PairingCurve>(
+    pk: &ProvingKey<E>,
+    witness: &[FieldWrapper<E::ScalarField>],
+    a_polys: &[Polynomial<E::ScalarField>],
+    b_polys: &[Polynomial<E::ScalarField>],
+    c_polys: &[Polynomial<E::ScalarField>],
     public_inputs: usize,
     seed: &[u8; 32],
-) -> Result<Proof, Groth16Error> {
+) -> Result<Proof<E>, Groth16Error> {
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -240,20 +461,20 @@ pub fn generate_proof_test(
 }
 
 /// Computes the witness polynomial by linearly combining QAP polynomials with witness values
-fn compute_witness_polynomial(
-    polys: &[Polynomial<Fr>],
-    witness: &[FieldWrapper<Fr>],
-) -> Polynomial<Fr> {
-    let mut result_coeffs = vec![FieldWrapper::<Fr>::zero(); polys.len()];
-
-    for (i, poly) in polys.iter().enumerate() {
-        if i < witness.len() {
-            let w = &witness[i];
-            for (j, coeff) in poly.coeffs.iter().enumerate() {
-                if j < result_coeffs.len() {
-                    result_coeffs[j] = result_coeffs[j].clone() + coeff.clone() * w.clone();
-                }
-            }
+fn compute_witness_polynomial<F: PrimeField>(
+    polys: &[Polynomial<F>],
+    witness: &[FieldWrapper<F>],
+) -> Polynomial<F> {
+    let len = polys
+        .iter()
+        .map(|poly| poly.coeffs.len())
+        .max()
+        .unwrap_or(0);
+    let mut result_coeffs = vec![FieldWrapper::<F>::zero(); len];
+
+    for (poly, w) in polys.iter().zip(witness) {
+        for (j, coeff) in poly.coeffs.iter().enumerate() {
+            result_coeffs[j] = result_coeffs[j].clone() + coeff.clone() * w.clone();
         }
     }
 
@@ -261,26 +482,26 @@ fn compute_witness_polynomial(
 }
 
 /// Performs polynomial division
-fn divide_polynomials(
-    dividend: &Polynomial<Fr>,
-    divisor: &Polynomial<Fr>,
-) -> Result<(Polynomial<Fr>, Polynomial<Fr>), String> {
+fn divide_polynomials<F: PrimeField>(
+    dividend: &Polynomial<F>,
+    divisor: &Polynomial<F>,
+) -> Result<(Polynomial<F>, Polynomial<F>), String> {
     if divisor.is_zero() {
         return Err("Division by zero".to_string());
     }
 
     if dividend.is_zero() {
         return Ok((
-            Polynomial::<Fr>::new(vec![FieldWrapper::<Fr>::zero()]),
-            Polynomial::<Fr>::new(vec![FieldWrapper::<Fr>::zero()]),
+            Polynomial::<F>::new(vec![FieldWrapper::<F>::zero()]),
+            Polynomial::<F>::new(vec![FieldWrapper::<F>::zero()]),
         ));
     }
 
     let mut remainder = dividend.clone();
-    let mut quotient_coeffs = vec![FieldWrapper::<Fr>::zero(); dividend.degree() + 1];
+    let mut quotient_coeffs = vec![FieldWrapper::<F>::zero(); dividend.degree() + 1];
 
     let divisor_degree = divisor.degree();
-    let zero = FieldWrapper::<Fr>::zero();
+    let zero = FieldWrapper::<F>::zero();
     let divisor_leading = divisor
         .coeffs
         .iter()
@@ -298,7 +519,7 @@ fn divide_polynomials(
             .unwrap_or(&zero);
 
         let coeff = remainder_leading.clone()
-            * FieldWrapper::<Fr>::from(
+            * FieldWrapper::<F>::from(
                 divisor_leading
                     .value
                     .inverse()
@@ -308,9 +529,9 @@ fn divide_polynomials(
         let degree_diff = remainder_degree - divisor_degree;
         quotient_coeffs[degree_diff] = quotient_coeffs[degree_diff].clone() + coeff.clone();
 
-        let mut term_coeffs = vec![FieldWrapper::<Fr>::zero(); degree_diff + 1];
+        let mut term_coeffs = vec![FieldWrapper::<F>::zero(); degree_diff + 1];
         term_coeffs[degree_diff] = coeff;
-        let term = Polynomial::<Fr>::new(term_coeffs);
+        let term = Polynomial::<F>::new(term_coeffs);
 
         let product = term * divisor.clone();
         remainder = remainder - product;
@@ -320,13 +541,14 @@ fn divide_polynomials(
         quotient_coeffs.pop();
     }
 
-    Ok((Polynomial::<Fr>::new(quotient_coeffs), remainder))
+    Ok((Polynomial::<F>::new(quotient_coeffs), remainder))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::setup::trusted_setup_test;
+    use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
     use groth16_qap::r1cs_to_qap;
     use groth16_r1cs::constraint::R1CSConstraint;
 
@@ -344,7 +566,8 @@ mod tests {
 
         // Trusted setup
         let seed = [42u8; 32];
-        let (pk, _vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, _vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Generate witness for a=3, b=4, c=12
         let witness = vec![
@@ -376,7 +599,8 @@ mod tests {
         let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
 
         let seed = [42u8; 32];
-        let (pk, _vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, _vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         let witness = vec![
             FieldWrapper::<Fr>::from(1u64),
@@ -394,4 +618,261 @@ mod tests {
         // Should be identical
         assert_eq!(proof1, proof2);
     }
+
+    #[test]
+    fn test_unblinded_proof_is_byte_identical() {
+        use crate::verify::verify_proof;
+
+        // a × b = c with witness [1, c, a, b]
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+
+        let constraints = vec![c1.clone(), c1.clone()];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(12u64),
+            FieldWrapper::<Fr>::from(3u64),
+            FieldWrapper::<Fr>::from(4u64),
+        ];
+
+        let proof1 =
+            generate_proof_unblinded(&pk, &witness, &a_polys, &b_polys, &c_polys, 1).unwrap();
+        let proof2 =
+            generate_proof_unblinded(&pk, &witness, &a_polys, &b_polys, &c_polys, 1).unwrap();
+
+        assert_eq!(proof1.to_bytes(), proof2.to_bytes());
+
+        // Unblinded proofs differ from blinded ones
+        let blinded =
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        assert_ne!(proof1, blinded);
+
+        // Both still verify
+        let public_inputs = vec![FieldWrapper::<Fr>::from(12u64)];
+        assert!(verify_proof(&vk, &proof1, &public_inputs).unwrap());
+        assert!(verify_proof(&vk, &proof2, &public_inputs).unwrap());
+    }
+
+    #[test]
+    fn test_division_polynomials() {
+        let dividend = Polynomial::<ark_bn254::Fr>::new(vec![
+            FieldWrapper::<ark_bn254::Fr>::zero() - FieldWrapper::<ark_bn254::Fr>::one(),
+            FieldWrapper::<ark_bn254::Fr>::zero(),
+            FieldWrapper::<ark_bn254::Fr>::one(),
+        ]);
+
+        let divisor = Polynomial::<ark_bn254::Fr>::new(vec![
+            FieldWrapper::<ark_bn254::Fr>::zero() - FieldWrapper::<ark_bn254::Fr>::one(),
+            FieldWrapper::<ark_bn254::Fr>::one(),
+        ]);
+
+        let (quotient, remainder) = divide_polynomials(&dividend, &divisor).unwrap();
+
+        assert_eq!(quotient.degree(), 1);
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn test_proof_bytes_round_trip() {
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(1, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(3, FieldWrapper::<Fr>::from(1u64));
+
+        let constraints = vec![c1.clone(), c1.clone()];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk, _vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(3u64),
+            FieldWrapper::<Fr>::from(4u64),
+            FieldWrapper::<Fr>::from(12u64),
+        ];
+        let proof =
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), proof.size_bytes());
+        assert_eq!(Proof::from_bytes(&bytes).unwrap(), proof);
+
+        // Corrupting any byte of B's x-coordinate must be rejected, either
+        // as an invalid encoding or as a point outside the subgroup
+        for i in 32..96 {
+            let mut corrupted = bytes.clone();
+            corrupted[i] ^= 0x01;
+            assert!(matches!(
+                Proof::<Bn254>::from_bytes(&corrupted),
+                Err(Groth16Error::SerializationError(_))
+            ));
+        }
+
+        // Truncated and over-long inputs are rejected too
+        assert!(Proof::<Bn254>::from_bytes(&bytes[..100]).is_err());
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(Proof::<Bn254>::from_bytes(&extended).is_err());
+    }
+
+    #[test]
+    fn test_truncated_proving_key_is_rejected() {
+        // Two products sharing the output, so h_query is non-empty:
+        // x2·x3 = x1 and x4·x5 = x1 with witness [1, 12, 3, 4, 2, 6]
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+        let mut c2 = R1CSConstraint::<Fr>::new();
+        c2.add_a_variable(4, FieldWrapper::<Fr>::from(1u64));
+        c2.add_b_variable(5, FieldWrapper::<Fr>::from(1u64));
+        c2.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&[c1, c2], 6).unwrap();
+        let seed = [42u8; 32];
+        let (pk, _vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 2, 1, &seed).unwrap();
+        let witness: Vec<_> = [1u64, 12, 3, 4, 2, 6]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+
+        // Two constraints give a QAP of degree 2
+        assert!(pk.validate(6, 2).is_ok());
+        assert!(generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).is_ok());
+
+        let mut short_h = pk.clone();
+        short_h.h_query.pop();
+        assert!(matches!(
+            generate_proof_test(&short_h, &witness, &a_polys, &b_polys, &c_polys, 1, &seed),
+            Err(Groth16Error::InvalidProvingKey {
+                query: "h_query",
+                expected: 1,
+                actual: 0
+            })
+        ));
+
+        let mut short_a = pk;
+        short_a.a_query.pop();
+        assert!(matches!(
+            short_a.validate(6, 2),
+            Err(Groth16Error::InvalidProvingKey {
+                query: "a_query",
+                ..
+            })
+        ));
+    }
+
+    /// Records the name and field names of every span created
+    #[cfg(feature = "tracing")]
+    struct SpanRecorder(std::sync::Mutex<Vec<(&'static str, Vec<&'static str>)>>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let metadata = span.metadata();
+            let fields = metadata.fields().iter().map(|f| f.name()).collect();
+            let mut spans = self.0.lock().unwrap();
+            spans.push((metadata.name(), fields));
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+        fn event(&self, _: &tracing::Event<'_>) {}
+        fn enter(&self, _: &tracing::span::Id) {}
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_prove_and_verify_emit_spans() {
+        use crate::verify::verify_proof;
+        use std::sync::{Arc, Mutex};
+
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&[c1.clone(), c1], 4).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 2, 1, &seed).unwrap();
+        let witness: Vec<_> = [1u64, 12, 3, 4]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+
+        let recorder = Arc::new(SpanRecorder(Mutex::new(Vec::new())));
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let proof =
+                generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+            verify_proof(&vk, &proof, &witness[1..2]).unwrap();
+        });
+
+        let spans = recorder.0.lock().unwrap();
+        let names: Vec<_> = spans.iter().map(|(name, _)| *name).collect();
+        for expected in [
+            "groth16::prove",
+            "msm",
+            "witness_polynomials",
+            "division",
+            "h_msm",
+            "groth16::verify",
+            "public_input_msm",
+            "pairings",
+        ] {
+            assert!(names.contains(&expected), "missing span {expected}");
+        }
+
+        let (_, prove_fields) = spans.iter().find(|(n, _)| *n == "groth16::prove").unwrap();
+        assert_eq!(prove_fields, &["num_variables", "num_constraints"]);
+    }
+
+    #[test]
+    fn test_proof_id_and_display() {
+        use ark_ec::AffineRepr;
+
+        let g1 = G1Affine::generator();
+        let g2 = G2Affine::generator();
+        let proof = |k: u64| Proof::<Bn254> {
+            a: (g1 * Fr::from(k)).into_affine(),
+            b: g2,
+            c: g1,
+        };
+
+        assert_eq!(proof(3).id(), proof(3).id());
+        assert_ne!(proof(3).id(), proof(4).id());
+
+        let shown = proof(3).to_string();
+        let bytes = proof(3).to_bytes();
+        let hex = |range: std::ops::Range<usize>| {
+            bytes[range]
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>()
+        };
+        assert_eq!(
+            shown,
+            format!(
+                "Proof(a={}…, b={}…, c={}…)",
+                hex(0..8),
+                hex(32..40),
+                hex(96..104)
+            )
+        );
+    }
 }
@@ -0,0 +1,107 @@
+use ark_bn254::Fr;
+use groth16_math::fields::FieldWrapper;
+
+/// The public inputs of a statement, without the rest of the witness.
+///
+/// Verification only ever needs the public field elements, so this is what
+/// should travel alongside a proof. Circuits expose their public values in
+/// witness order (e.g. `MultiplierCircuit::public_inputs()` returns `[c]`),
+/// which can be wrapped directly with [`PublicInput::new`].
+///
+/// Some callers carry the leading constant 1 of the witness along with the
+/// public values; [`PublicInput::with_constant`] accepts that layout and
+/// [`inputs`](PublicInput::inputs) always returns the values without it, as
+/// expected by [`verify_proof`](crate::verify::verify_proof).
+///
+/// # Example
+/// ```rust,ignore
+/// let public = PublicInput::new(circuit.public_inputs());
+/// let is_valid = verify_proof(&vk, &proof, public.inputs())?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct PublicInput {
+    /// Public field elements, in witness order
+    values: Vec<FieldWrapper<Fr>>,
+    /// Whether `values[0]` is the constant 1
+    includes_constant: bool,
+}
+
+impl PublicInput {
+    /// Wraps public values that do not include the constant 1.
+    pub fn new(values: Vec<FieldWrapper<Fr>>) -> Self {
+        Self {
+            values,
+            includes_constant: false,
+        }
+    }
+
+    /// Wraps public values prefixed by the constant 1 (witness layout).
+    ///
+    /// Returns `None` if the first value is missing or is not 1.
+    pub fn with_constant(values: Vec<FieldWrapper<Fr>>) -> Option<Self> {
+        match values.first() {
+            Some(first) if first.value == Fr::from(1u64) => Some(Self {
+                values,
+                includes_constant: true,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether the stored values include the leading constant 1.
+    pub fn includes_constant(&self) -> bool {
+        self.includes_constant
+    }
+
+    /// Returns the public inputs without the constant, ready for verification.
+    pub fn inputs(&self) -> &[FieldWrapper<Fr>] {
+        if self.includes_constant {
+            &self.values[1..]
+        } else {
+            &self.values
+        }
+    }
+
+    /// Returns the number of public inputs, not counting the constant.
+    pub fn len(&self) -> usize {
+        self.inputs().len()
+    }
+
+    /// Returns true if there are no public inputs besides the constant.
+    pub fn is_empty(&self) -> bool {
+        self.inputs().is_empty()
+    }
+}
+
+impl AsRef<[FieldWrapper<Fr>]> for PublicInput {
+    fn as_ref(&self) -> &[FieldWrapper<Fr>] {
+        self.inputs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_is_stripped() {
+        let public = PublicInput::with_constant(vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(12u64),
+        ])
+        .unwrap();
+
+        assert!(public.includes_constant());
+        assert_eq!(public.len(), 1);
+        assert_eq!(public.inputs()[0].value, Fr::from(12u64));
+
+        let plain = PublicInput::new(vec![FieldWrapper::<Fr>::from(12u64)]);
+        assert_eq!(plain.inputs()[0].value, public.inputs()[0].value);
+    }
+
+    #[test]
+    fn test_with_constant_requires_leading_one() {
+        assert!(PublicInput::with_constant(vec![]).is_none());
+        assert!(PublicInput::with_constant(vec![FieldWrapper::<Fr>::from(12u64)]).is_none());
+    }
+}
@@ -1,12 +1,73 @@
 use crate::error::Groth16Error;
 use crate::keys::{ProvingKey, VerificationKey};
-use ark_bn254::{Fr, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2};
+use ark_ec::pairing::Pairing;
 use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::{BigInteger, Field, PrimeField, UniformRand, Zero};
+use ark_ff::{BigInteger, PrimeField, Zero};
 use groth16_math::fields::FieldWrapper;
+use groth16_math::pairing::{batch_to_affine, CurveScalar};
 use groth16_math::polynomial::Polynomial;
-use groth16_qap::target_polynomial;
+use groth16_qap::{r1cs_to_qap, target_polynomial};
+use groth16_r1cs::constraint::R1CSConstraint;
 use rand::Rng;
+use zeroize::Zeroizing;
+
+/// The proving and verification keys produced by a setup over curve `E`
+pub type KeyPair<E> = (ProvingKey<E>, VerificationKey<E>);
+
+/// Source of the secret scalars sampled by the trusted setup.
+///
+/// Every `rand::Rng` is a `SetupRng` through the blanket impl, so existing
+/// callers pass their RNG as before. Implement it directly to plug in a
+/// generator that does not speak the `rand` traits, such as a hardware
+/// CSPRNG on an embedded target. Setup draws α, β, γ, δ and τ, in that
+/// order, with one call each, and fails with
+/// [`Groth16Error::ZeroSetupSecret`] if γ or δ comes out as zero.
+pub trait SetupRng<F: PrimeField> {
+    /// Returns a uniformly random field element
+    fn fill_scalar(&mut self) -> F;
+}
+
+impl<F: PrimeField, R: Rng + ?Sized> SetupRng<F> for R {
+    fn fill_scalar(&mut self) -> F {
+        F::rand(self)
+    }
+}
+
+/// Tuning knobs for [`trusted_setup_with_options`].
+///
+/// Setup is dominated by multiplications of the fixed G1 and G2 generators
+/// by secret-derived scalars. Since the base never changes, a table of
+/// window multiples d·2^(w·k)·G can be built once and each multiplication
+/// reduced to one table lookup and addition per w-bit window, with no
+/// doublings. Building the tables costs roughly 2^w additions per window, so
+/// it only pays off for circuits with more than a handful of variables.
+///
+/// The table options only affect speed: the generated keys are identical
+/// either way. `max_degree` is a guard, rejecting circuits whose QAP degree
+/// (their constraint count) would make the H query too large to allocate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SetupOptions {
+    /// Window size in bits for the fixed-base tables (1..=16)
+    pub window_bits: usize,
+    /// Whether to precompute fixed-base tables for the generators
+    pub use_precomputed_tables: bool,
+    /// Largest QAP degree (the degree of the target polynomial t(x)) accepted
+    pub max_degree: usize,
+}
+
+/// Default bound on the QAP degree accepted by the trusted setup
+pub const MAX_DEGREE: usize = 1 << 20;
+
+impl Default for SetupOptions {
+    /// Plain double-and-add multiplication, as used by [`trusted_setup`].
+    fn default() -> Self {
+        Self {
+            window_bits: 4,
+            use_precomputed_tables: false,
+            max_degree: MAX_DEGREE,
+        }
+    }
+}
 
 /// Performs the trusted setup ceremony to generate proving and verification keys.
 ///
@@ -19,27 +80,45 @@ use rand::Rng;
 /// # Process
 /// 1. Generate random secrets: α, β, γ, δ in the scalar field
 /// 2. Generate random τ (tau) in the scalar field
-/// 3. Compute powers of τ: [1, τ, τ², ..., τⁿ] encrypted in G1 and G2
-/// 4. Evaluate QAP polynomials at τ and encrypt with the secrets
-/// 5. Compute division polynomials and encrypt them
-/// 6. Compute public input verification coefficients
+/// 3. Evaluate QAP polynomials at τ and encrypt Aⱼ(τ) and Bⱼ(τ)
+/// 4. Encrypt (β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ)) divided by γ for the public
+///    variables (the IC vector) and by δ for the private ones
+/// 5. Encrypt τⁱ·t(τ)/δ for the coefficients of the division polynomial
 ///
 /// # Arguments
 /// * `a_polys` - A-polynomials from QAP [A₀(x), ..., Aₘ(x)]
 /// * `b_polys` - B-polynomials from QAP [B₀(x), ..., Bₘ(x)]
 /// * `c_polys` - C-polynomials from QAP [C₀(x), ..., Cₘ(x)]
+/// * `num_constraints` - Number of R1CS constraints the QAP was built from
 /// * `num_inputs` - Number of public inputs (usually 1 for the constant 1)
-/// * `rng` - Random number generator
+/// * `rng` - Source of the secret scalars, any `rand::Rng` or a custom [`SetupRng`]
 ///
 /// # Returns
 /// * `Ok((pk, vk))` - Proving key and verification key
-/// * `Err(...)` - Error if setup fails
+/// * `Err(...)` - Error if setup fails, including
+///   [`Groth16Error::IcLengthMismatch`] if the IC vector does not hold exactly
+///   `num_inputs + 1` elements, [`Groth16Error::CircuitTooLarge`] if the QAP
+///   degree exceeds [`MAX_DEGREE`] and [`Groth16Error::ConstraintCountMismatch`]
+///   if the polynomials are too long to come from `num_constraints` constraints
+///
+/// The constraint count fixes the target polynomial t(x) and cannot be
+/// recovered from the polynomials themselves: interpolation drops trailing
+/// zero coefficients, and a t(x) with fewer roots than there are
+/// constraints would leave the remaining constraints unchecked.
+///
+/// The verification key always holds `num_inputs + 1` IC elements: one for
+/// the constant 1 followed by one per public input.
 ///
 /// # Security Warning
 /// The random secrets (α, β, γ, δ, τ) are "toxic waste" - they MUST be securely
 /// deleted after this ceremony. If an attacker obtains these secrets, they can
 /// forge proofs for any witness.
 ///
+/// The secret scalars are held in [`Zeroizing`] guards, so their memory is
+/// overwritten with zeros when this function returns (on success or error).
+/// Intermediate values derived from them (e.g. polynomial evaluations at τ)
+/// are not tracked and are dropped normally.
+///
 /// # Example
 /// ```rust,ignore
 /// use groth16_groth16::setup::trusted_setup;
@@ -49,7 +128,7 @@ use rand::Rng;
 /// let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, num_vars)?;
 ///
 /// // Perform trusted setup
-/// let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, 1, &mut rng)?;
+/// let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &mut rng)?;
 ///
 /// // pk is used to generate proofs
 /// // vk is used to verify proofs
@@ -57,16 +136,60 @@ use rand::Rng;
 /// // IMPORTANT: Securely delete all secrets (α, β, γ, δ, τ)
 /// # Ok::<(), Groth16Error>(())
 /// ```
-pub fn trusted_setup<R>(
-    a_polys: &[Polynomial<Fr>],
-    b_polys: &[Polynomial<Fr>],
-    c_polys: &[Polynomial<Fr>],
+pub fn trusted_setup<F, R>(
+    a_polys: &[Polynomial<F>],
+    b_polys: &[Polynomial<F>],
+    c_polys: &[Polynomial<F>],
+    num_constraints: usize,
     num_inputs: usize,
     rng: &mut R,
-) -> Result<(ProvingKey, VerificationKey), Groth16Error>
+) -> Result<KeyPair<F::Curve>, Groth16Error>
 where
-    R: Rng,
+    F: CurveScalar,
+    R: SetupRng<F> + ?Sized,
 {
+    trusted_setup_with_options(
+        a_polys,
+        b_polys,
+        c_polys,
+        num_constraints,
+        num_inputs,
+        &SetupOptions::default(),
+        rng,
+    )
+}
+
+/// Performs the trusted setup with explicit [`SetupOptions`].
+///
+/// Consumes the same randomness as [`trusted_setup`], so for the same RNG
+/// state the keys are identical regardless of the options chosen.
+///
+/// # Returns
+/// * `Ok((pk, vk))` - Proving key and verification key
+/// * `Err(Groth16Error::InvalidSetupOptions)` - If `window_bits` is out of range
+/// * `Err(Groth16Error::CircuitTooLarge)` - If the QAP degree exceeds `options.max_degree`
+/// * `Err(Groth16Error::ZeroSetupSecret)` - If the RNG draws zero for γ or δ
+/// * `Err(...)` - Any error [`trusted_setup`] can return
+pub fn trusted_setup_with_options<F, R>(
+    a_polys: &[Polynomial<F>],
+    b_polys: &[Polynomial<F>],
+    c_polys: &[Polynomial<F>],
+    num_constraints: usize,
+    num_inputs: usize,
+    options: &SetupOptions,
+    rng: &mut R,
+) -> Result<KeyPair<F::Curve>, Groth16Error>
+where
+    F: CurveScalar,
+    R: SetupRng<F> + ?Sized,
+{
+    if options.use_precomputed_tables && !(1..=16).contains(&options.window_bits) {
+        return Err(Groth16Error::InvalidSetupOptions(format!(
+            "window_bits must be between 1 and 16, got {}",
+            options.window_bits
+        )));
+    }
+
     // Validate input
     if a_polys.is_empty() || b_polys.is_empty() || c_polys.is_empty() {
         return Err(Groth16Error::EmptyPolynomials);
@@ -80,97 +203,120 @@ where
         ));
     }
 
-    if num_inputs > a_polys.len() - 1 {
+    if a_polys.len() < 2 || num_inputs > a_polys.len() - 1 {
         // num_inputs can be 0 (no public inputs except constant), but must not exceed available variables
-        // We subtract 1 because index 0 is always the constant 1
+        // We subtract 1 because index 0 is always the constant 1, and a
+        // circuit over the constant alone has nothing to prove
         return Err(Groth16Error::InvalidInputs(num_inputs));
     }
 
-    let num_vars = a_polys.len();
-    let num_constraints = num_vars - 2;
-
-    // Step 1: Generate random secrets (TOXIC WASTE)
-    let alpha = Fr::rand(rng);
-    let beta = Fr::rand(rng);
-    let gamma = Fr::rand(rng);
-    let delta = Fr::rand(rng);
-    let tau = Fr::rand(rng);
-
-    // Step 2: Compute powers of tau encrypted in G1 and G2
-    let _tau_powers_g1 = compute_powers_of_tau_g1(tau, num_constraints + 2);
-    let _tau_powers_g2 = compute_powers_of_tau_g2(tau, num_constraints + 2);
-
-    // Step 3: Encrypt the secrets with generators
-    let alpha_g1 = (G1Affine::generator() * alpha).into_affine();
-    let beta_g1 = (G1Affine::generator() * beta).into_affine();
-    let beta_g2 = (G2Affine::generator() * beta).into_affine();
-    let gamma_g2 = (G2Affine::generator() * gamma).into_affine();
-    let delta_g1 = (G1Affine::generator() * delta).into_affine();
-    let delta_g2 = (G2Affine::generator() * delta).into_affine();
-
-    // Step 4: Compute encrypted A-polynomials
-    // Use tau directly as Fr field element (no conversion needed)
-    let tau_field = FieldWrapper::<Fr>::from(tau);
-    let mut a_query = Vec::with_capacity(num_vars);
-    for poly in a_polys {
-        let eval = poly.evaluate(&tau_field);
-        let encrypted = (G1Affine::generator() * alpha * eval.value).into_affine();
-        a_query.push(encrypted);
+    let degree = qap_degree(num_constraints);
+    let min_degree = interpolated_degree(a_polys, b_polys, c_polys);
+    if num_constraints == 0 || min_degree > degree {
+        return Err(Groth16Error::ConstraintCountMismatch {
+            num_constraints,
+            min: min_degree,
+        });
     }
 
-    // Step 5: Compute encrypted B-polynomials in G1 and G2
-    let mut b_g1_query = Vec::with_capacity(num_vars);
-    let mut b_g2_query = Vec::with_capacity(num_vars);
-    for poly in b_polys {
-        let eval = poly.evaluate(&tau_field);
-
-        let encrypted_g1 = (G1Affine::generator() * beta * eval.value).into_affine();
-        b_g1_query.push(encrypted_g1);
-
-        let encrypted_g2 = (G2Affine::generator() * beta * eval.value).into_affine();
-        b_g2_query.push(encrypted_g2);
-    }
-
-    // Step 5.5: Compute encrypted C-polynomials in G1
-    let mut c_query = Vec::with_capacity(num_vars);
-    for poly in c_polys {
-        let eval = poly.evaluate(&tau_field);
-        let encrypted = (G1Affine::generator() * beta * eval.value).into_affine();
-        c_query.push(encrypted);
+    // Reject oversized circuits before allocating anything proportional to them
+    if degree > options.max_degree {
+        return Err(Groth16Error::CircuitTooLarge {
+            degree,
+            max: options.max_degree,
+        });
     }
 
-    // Step 6: Compute division polynomials
-    let target = target_polynomial::<Fr>(num_constraints);
-    let h_query =
-        compute_division_polynomials_encrypted(&target, num_constraints, tau_field.clone())?;
-
-    // Step 7: Compute IC for public inputs
+    // Step 1: Generate random secrets (TOXIC WASTE)
+    // Each secret is zeroized when its guard is dropped at the end of setup.
+    let alpha = Zeroizing::new(rng.fill_scalar());
+    let beta = Zeroizing::new(rng.fill_scalar());
+    let gamma = Zeroizing::new(rng.fill_scalar());
+    let delta = Zeroizing::new(rng.fill_scalar());
+    let tau = Zeroizing::new(rng.fill_scalar());
+
+    // A custom SetupRng can return zero, which has no inverse
+    let gamma_inv = Zeroizing::new(gamma.inverse().ok_or(Groth16Error::ZeroSetupSecret("γ"))?);
+    let delta_inv = Zeroizing::new(delta.inverse().ok_or(Groth16Error::ZeroSetupSecret("δ"))?);
+
+    // Step 2: Encrypt the secrets with generators
+    let generators = GeneratorMul::<F::Curve>::new(options);
+    let alpha_g1 = generators.g1(*alpha).into_affine();
+    let beta_g1 = generators.g1(*beta).into_affine();
+    let beta_g2 = generators.g2(*beta).into_affine();
+    let gamma_g2 = generators.g2(*gamma).into_affine();
+    let delta_g1 = generators.g1(*delta).into_affine();
+    let delta_g2 = generators.g2(*delta).into_affine();
+
+    // Step 3: Evaluate the QAP polynomials at τ
+    // Use tau directly as a scalar field element (no conversion needed)
+    let tau_field = FieldWrapper::<F>::from(*tau);
+    let evaluate = |polys: &[Polynomial<F>]| -> Vec<F> {
+        polys
+            .iter()
+            .map(|poly| poly.evaluate(&tau_field).value)
+            .collect()
+    };
+    let a_at_tau = evaluate(a_polys);
+    let b_at_tau = evaluate(b_polys);
+    let c_at_tau = evaluate(c_polys);
+
+    // Step 4: Encrypt Aⱼ(τ) in G1 and Bⱼ(τ) in G1 and G2
+    let a_query: Vec<_> = a_at_tau.iter().map(|a| generators.g1(*a)).collect();
+    let b_g1_query: Vec<_> = b_at_tau.iter().map(|b| generators.g1(*b)).collect();
+    let b_g2_query: Vec<_> = b_at_tau.iter().map(|b| generators.g2(*b)).collect();
+    let a_query = batch_to_affine(&a_query);
+    let b_g1_query = batch_to_affine(&b_g1_query);
+    let b_g2_query = batch_to_affine(&b_g2_query);
+
+    // Step 5: Combine each variable's evaluations as β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ)
     //
-    // The IC (Input Consistency) vector encodes public inputs for verification.
     // Following the standard Groth16 convention where witness is structured as:
     // [1, public_inputs..., private_inputs...]
     //
-    // For the IC computation:
-    // - IC[0] = β·G₁ (for the constant 1 at witness[0])
-    // - IC[i] = β·Aᵢ(τ)·G₁ for i = 1..num_inputs
-    //
-    // Note: The full Groth16 formula is IC[i] = {β·Aᵢ(τ) + α·Kᵢ(τ)}·G₁,
-    // but for this educational implementation we use the simplified version.
-
-    let mut ic = Vec::with_capacity(num_inputs + 1);
-
-    // IC[0] = β·G₁ for constant 1
-    ic.push((G1Affine::generator() * beta).into_affine());
+    // - IC[j] = (β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ))/γ·G₁ for j = 0..=num_inputs,
+    //   so the verifier can add in the public part of the witness
+    // - c_query[j] = (β·Aⱼ(τ) + α·Bⱼ(τ) + Cⱼ(τ))/δ·G₁ for the private j;
+    //   the public entries stay at the identity so that the prover can
+    //   index c_query by witness position
+    let combined: Vec<F> = a_at_tau
+        .iter()
+        .zip(&b_at_tau)
+        .zip(&c_at_tau)
+        .map(|((a, b), c)| *beta * a + *alpha * b + c)
+        .collect();
 
-    // For each public input i (1..num_inputs), compute IC[i]
-    // Public inputs are at witness indices 1..num_inputs
-    for a_poly in a_polys.iter().take(num_inputs + 1).skip(1) {
-        // Public input at witness index i corresponds to a_polys[i]
-        let a_eval = a_poly.evaluate(&tau_field).value;
+    let ic: Vec<_> = combined[..=num_inputs]
+        .iter()
+        .map(|value| generators.g1(*value * *gamma_inv))
+        .collect();
+    let ic = batch_to_affine(&ic);
 
-        // IC[i] = β·Aᵢ(τ)·G₁
-        let ic_point = (G1Affine::generator() * beta * a_eval).into_affine();
-        ic.push(ic_point);
+    let c_query: Vec<_> = combined
+        .iter()
+        .enumerate()
+        .map(|(j, value)| {
+            if j <= num_inputs {
+                <F::Curve as Pairing>::G1::zero()
+            } else {
+                generators.g1(*value * *delta_inv)
+            }
+        })
+        .collect();
+    let c_query = batch_to_affine(&c_query);
+
+    // Step 6: Compute the H query [τⁱ·t(τ)/δ·G₁] for i = 0..degree-1
+    // H(x) = p(x)/t(x) has degree at most degree - 2 for any witness
+    let target_at_tau = target_polynomial::<F>(degree).evaluate(&tau_field).value;
+    let h_query = compute_h_query(*tau, target_at_tau * *delta_inv, degree, &generators);
+
+    // IC must hold one element for the constant plus one per public input,
+    // otherwise every proof against this key would fail to verify
+    if ic.len() != num_inputs + 1 {
+        return Err(Groth16Error::IcLengthMismatch {
+            expected: num_inputs + 1,
+            actual: ic.len(),
+        });
     }
 
     // Construct keys
@@ -199,180 +345,488 @@ where
 }
 
 /// Performs a deterministic trusted setup for testing purposes.
-pub fn trusted_setup_test(
-    a_polys: &[Polynomial<Fr>],
-    b_polys: &[Polynomial<Fr>],
-    c_polys: &[Polynomial<Fr>],
+pub fn trusted_setup_test<F: CurveScalar>(
+    a_polys: &[Polynomial<F>],
+    b_polys: &[Polynomial<F>],
+    c_polys: &[Polynomial<F>],
+    num_constraints: usize,
     num_inputs: usize,
     seed: &[u8; 32],
-) -> Result<(ProvingKey, VerificationKey), Groth16Error> {
+) -> Result<KeyPair<F::Curve>, Groth16Error> {
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
     let mut rng = ChaCha8Rng::from_seed(*seed);
-    trusted_setup(a_polys, b_polys, c_polys, num_inputs, &mut rng)
+    trusted_setup(
+        a_polys,
+        b_polys,
+        c_polys,
+        num_constraints,
+        num_inputs,
+        &mut rng,
+    )
 }
 
-/// Computes powers of tau encrypted in G1
-fn compute_powers_of_tau_g1(tau: Fr, degree: usize) -> Vec<G1Affine> {
-    let mut result = Vec::with_capacity(degree);
-    let mut current = G1::from(G1Affine::generator());
-
-    for _ in 0..degree {
-        result.push(current.into_affine());
-        current *= tau;
-    }
-
-    result
+/// Runs the trusted setup directly on R1CS constraints.
+///
+/// Converts the constraints to a QAP with [`r1cs_to_qap`] and passes the
+/// resulting polynomials to [`trusted_setup`]. QAP failures (for example
+/// an empty constraint system) surface as [`Groth16Error::Qap`].
+pub fn trusted_setup_from_r1cs<F, R>(
+    constraints: &[R1CSConstraint<F>],
+    num_variables: usize,
+    num_inputs: usize,
+    rng: &mut R,
+) -> Result<KeyPair<F::Curve>, Groth16Error>
+where
+    F: CurveScalar,
+    R: SetupRng<F> + ?Sized,
+{
+    let (a_polys, b_polys, c_polys) = r1cs_to_qap(constraints, num_variables)?;
+    trusted_setup(
+        &a_polys,
+        &b_polys,
+        &c_polys,
+        constraints.len(),
+        num_inputs,
+        rng,
+    )
 }
 
-/// Computes powers of tau encrypted in G2
-fn compute_powers_of_tau_g2(tau: Fr, degree: usize) -> Vec<G2Affine> {
-    let mut result = Vec::with_capacity(degree);
-    let mut current = G2::from(G2Affine::generator());
+/// Returns the degree n of the QAP target polynomial t(x) = (x - 1)···(x - n)
+/// for a system of `num_constraints` constraints.
+///
+/// [`r1cs_to_qap`] interpolates over one point per constraint, padding a
+/// lone constraint with a copy of itself, so n is the constraint count but
+/// at least 2. The setup sizes the H query from this degree, and the prover
+/// divides by the same t(x).
+pub fn qap_degree(num_constraints: usize) -> usize {
+    num_constraints.max(2)
+}
 
-    for _ in 0..degree {
-        result.push(current.into_affine());
-        current *= tau;
-    }
+/// Returns the length of the longest QAP polynomial.
+///
+/// Only a lower bound on the QAP degree: interpolation drops trailing zero
+/// coefficients, so the polynomials of n constraints can be much shorter
+/// than n.
+pub(crate) fn interpolated_degree<F: PrimeField>(
+    a_polys: &[Polynomial<F>],
+    b_polys: &[Polynomial<F>],
+    c_polys: &[Polynomial<F>],
+) -> usize {
+    a_polys
+        .iter()
+        .chain(b_polys)
+        .chain(c_polys)
+        .map(|poly| poly.degree() + 1)
+        .max()
+        .unwrap_or(0)
+}
 
-    result
+/// Multiplies the G1 and G2 generators by scalars, optionally via fixed-base tables
+struct GeneratorMul<E: Pairing> {
+    g1_table: Option<FixedBaseTable<E::G1>>,
+    g2_table: Option<FixedBaseTable<E::G2>>,
 }
 
-/// Computes division polynomials and encrypts them
-fn compute_division_polynomials_encrypted(
-    target: &Polynomial<Fr>,
-    num_constraints: usize,
-    tau: FieldWrapper<Fr>,
-) -> Result<Vec<G1Affine>, Groth16Error> {
-    let mut result = Vec::new();
-
-    for j in 0..num_constraints.saturating_sub(2) {
-        let j_field = FieldWrapper::<Fr>::from(j as u64);
-        let divisor = Polynomial::<Fr>::new(vec![
-            FieldWrapper::<Fr>::zero() - j_field.clone(),
-            FieldWrapper::<Fr>::one(),
-        ]);
-
-        match divide_polynomials(target, &divisor) {
-            Ok((quotient, _remainder)) => {
-                let h_j_at_tau = quotient.evaluate(&tau);
-                let encrypted = (G1Affine::generator() * h_j_at_tau.value).into_affine();
-                result.push(encrypted);
+impl<E: Pairing> GeneratorMul<E> {
+    fn new(options: &SetupOptions) -> Self {
+        if options.use_precomputed_tables {
+            Self {
+                g1_table: Some(FixedBaseTable::new(
+                    E::G1Affine::generator().into(),
+                    options.window_bits,
+                )),
+                g2_table: Some(FixedBaseTable::new(
+                    E::G2Affine::generator().into(),
+                    options.window_bits,
+                )),
             }
-            Err(e) => {
-                return Err(Groth16Error::DivisionError(format!(
-                    "Failed to divide t(x) by (x - {}): {}",
-                    j, e
-                )))
+        } else {
+            Self {
+                g1_table: None,
+                g2_table: None,
             }
         }
     }
 
-    Ok(result)
-}
+    /// Computes scalar·G₁, left in projective form for batch normalization
+    fn g1(&self, scalar: E::ScalarField) -> E::G1 {
+        match &self.g1_table {
+            Some(table) => table.mul(scalar),
+            None => E::G1Affine::generator() * scalar,
+        }
+    }
 
-/// Performs polynomial division
-fn divide_polynomials(
-    dividend: &Polynomial<Fr>,
-    divisor: &Polynomial<Fr>,
-) -> Result<(Polynomial<Fr>, Polynomial<Fr>), String> {
-    if divisor.is_zero() {
-        return Err("Division by zero".to_string());
+    /// Computes scalar·G₂, left in projective form for batch normalization
+    fn g2(&self, scalar: E::ScalarField) -> E::G2 {
+        match &self.g2_table {
+            Some(table) => table.mul(scalar),
+            None => E::G2Affine::generator() * scalar,
+        }
     }
+}
 
-    if dividend.is_zero() {
-        return Ok((
-            Polynomial::<Fr>::new(vec![FieldWrapper::<Fr>::zero()]),
-            Polynomial::<Fr>::new(vec![FieldWrapper::<Fr>::zero()]),
-        ));
+/// Precomputed multiples of a fixed base for windowed scalar multiplication.
+///
+/// `windows[k][d]` holds d·2^(w·k)·base, so scalar·base is the sum of one
+/// entry per window, indexed by that window's w-bit digit of the scalar.
+/// The lookups are indexed by secret digits and therefore not constant-time.
+struct FixedBaseTable<G: CurveGroup> {
+    window_bits: usize,
+    windows: Vec<Vec<G::Affine>>,
+}
+
+impl<G: CurveGroup> FixedBaseTable<G> {
+    fn new(base: G, window_bits: usize) -> Self {
+        let num_windows = (G::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(window_bits);
+        let mut windows = Vec::with_capacity(num_windows);
+        let mut window_base = base;
+
+        for _ in 0..num_windows {
+            let mut multiples = Vec::with_capacity(1 << window_bits);
+            let mut current = G::zero();
+            for _ in 0..(1 << window_bits) {
+                multiples.push(current);
+                current += window_base;
+            }
+            windows.push(G::normalize_batch(&multiples));
+
+            for _ in 0..window_bits {
+                window_base.double_in_place();
+            }
+        }
+
+        Self {
+            window_bits,
+            windows,
+        }
     }
 
-    let mut remainder = dividend.clone();
-    let mut quotient_coeffs = vec![FieldWrapper::<Fr>::zero(); dividend.degree() + 1];
+    fn mul(&self, scalar: G::ScalarField) -> G {
+        let bits = scalar.into_bigint().to_bits_le();
+        let mut result = G::zero();
 
-    let divisor_degree = divisor.degree();
-    let zero = FieldWrapper::<Fr>::zero();
-    let divisor_leading = divisor
-        .coeffs
-        .iter()
-        .rev()
-        .find(|c| !c.value.is_zero())
-        .unwrap_or(&zero);
-
-    while remainder.degree() >= divisor_degree && !remainder.is_zero() {
-        let remainder_degree = remainder.degree();
-        let remainder_leading = remainder
-            .coeffs
-            .iter()
-            .rev()
-            .find(|c| !c.value.is_zero())
-            .unwrap_or(&zero);
-
-        let coeff = remainder_leading.clone()
-            * FieldWrapper::<Fr>::from(
-                divisor_leading
-                    .value
-                    .inverse()
-                    .expect("Divisor leading coefficient should never be zero"),
-            );
-
-        let degree_diff = remainder_degree - divisor_degree;
-        quotient_coeffs[degree_diff] = quotient_coeffs[degree_diff].clone() + coeff.clone();
-
-        let mut term_coeffs = vec![FieldWrapper::<Fr>::zero(); degree_diff + 1];
-        term_coeffs[degree_diff] = coeff;
-        let term = Polynomial::<Fr>::new(term_coeffs);
-
-        let product = term * divisor.clone();
-        remainder = remainder - product;
+        for (multiples, digit_bits) in self.windows.iter().zip(bits.chunks(self.window_bits)) {
+            let digit = digit_bits
+                .iter()
+                .rev()
+                .fold(0usize, |digit, &bit| (digit << 1) | bit as usize);
+            result += multiples[digit];
+        }
+
+        result
     }
+}
 
-    while quotient_coeffs.len() > 1 && quotient_coeffs.last().unwrap().value.is_zero() {
-        quotient_coeffs.pop();
+/// Computes [τⁱ·scale·G₁] for i = 0..degree-1, the H query for scale = t(τ)/δ
+fn compute_h_query<E: Pairing>(
+    tau: E::ScalarField,
+    scale: E::ScalarField,
+    degree: usize,
+    generators: &GeneratorMul<E>,
+) -> Vec<E::G1Affine> {
+    let mut result = Vec::with_capacity(degree.saturating_sub(1));
+    let mut current = scale;
+
+    for _ in 1..degree {
+        result.push(generators.g1(current));
+        current *= tau;
     }
 
-    Ok((Polynomial::<Fr>::new(quotient_coeffs), remainder))
+    batch_to_affine(&result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ark_bn254::{Fr, G1Affine};
+    use ark_ec::AffineRepr;
     use groth16_qap::r1cs_to_qap;
     use groth16_r1cs::constraint::R1CSConstraint;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
+    /// x₁·x₁ = x₂, x₂·x₂ = x₃, ... with `n` constraints over n + 2 variables.
+    ///
+    /// Each xᵢ appears in one constraint only, so the QAP polynomials are
+    /// Lagrange basis polynomials and the QAP degree is exactly n.
+    fn squaring_chain(n: usize) -> Vec<R1CSConstraint<Fr>> {
+        (1..=n)
+            .map(|i| {
+                let mut constraint = R1CSConstraint::<Fr>::new();
+                constraint.add_a_variable(i, FieldWrapper::<Fr>::from(1u64));
+                constraint.add_b_variable(i, FieldWrapper::<Fr>::from(1u64));
+                constraint.add_c_variable(i + 1, FieldWrapper::<Fr>::from(1u64));
+                constraint
+            })
+            .collect()
+    }
+
     #[test]
     fn test_trusted_setup_structure() {
-        // Create simple QAP with 4 constraints to get division polynomials
-        let mut c1 = R1CSConstraint::<ark_bn254::Fr>::new();
-        c1.add_a_variable(1, FieldWrapper::<ark_bn254::Fr>::from(1u64));
-        c1.add_b_variable(2, FieldWrapper::<ark_bn254::Fr>::from(1u64));
-        c1.add_c_variable(3, FieldWrapper::<ark_bn254::Fr>::from(1u64));
-
-        let c2 = c1.clone();
-        let c3 = c1.clone();
-        let c4 = c1.clone();
-
-        let constraints = vec![c1, c2, c3, c4];
-        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 6).unwrap();
+        // Create a QAP with 4 constraints to get division polynomials
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&squaring_chain(4), 6).unwrap();
+        assert_eq!(interpolated_degree(&a_polys, &b_polys, &c_polys), 4);
 
         // Perform trusted setup
         let seed = [42u8; 32];
         let mut rng = ChaCha8Rng::from_seed(seed);
-        let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, 1, &mut rng).unwrap();
+        let (pk, vk) = trusted_setup(&a_polys, &b_polys, &c_polys, 4, 1, &mut rng).unwrap();
 
         // Verify pk structure
         assert_eq!(pk.a_query.len(), 6);
         assert_eq!(pk.b_g1_query.len(), 6);
         assert_eq!(pk.b_g2_query.len(), 6);
-        // num_constraints = 6 - 2 = 4, so h_query has 4 - 2 = 2 elements
-        assert_eq!(pk.h_query.len(), 2);
+        assert_eq!(pk.c_query.len(), 6);
+        // The QAP has degree 4, so H(x) has at most 3 coefficients
+        assert_eq!(pk.h_query.len(), 3);
+
+        // The constant and the public input are checked through IC, not C
+        assert!(pk.c_query[..2].iter().all(|p| p.is_zero()));
+        assert!(pk.c_query[2..].iter().all(|p| !p.is_zero()));
 
         // Verify vk structure
         // With num_inputs=1, IC should have 2 elements: IC[0] for constant, IC[1] for public input
         assert_eq!(vk.ic.len(), 2);
+
+        // Polynomials of degree 3 cannot come from 3 constraints
+        assert!(matches!(
+            trusted_setup(&a_polys, &b_polys, &c_polys, 3, 1, &mut rng),
+            Err(Groth16Error::ConstraintCountMismatch {
+                num_constraints: 3,
+                min: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_constraint_count_fixes_target_polynomial() {
+        use crate::prove::generate_proof_test;
+        use crate::verify::verify_proof;
+
+        // (i·x₂ + x₃)·(i·x₄ + x₅) = x₁ for i = 1, 2, 3. Every coefficient is
+        // linear in i, so interpolation trims the polynomials to degree 1
+        let constraints: Vec<_> = (1..=3u64)
+            .map(|i| {
+                let mut constraint = R1CSConstraint::<Fr>::new();
+                constraint.add_a_variable(2, FieldWrapper::<Fr>::from(i));
+                constraint.add_a_variable(3, FieldWrapper::<Fr>::from(1u64));
+                constraint.add_b_variable(4, FieldWrapper::<Fr>::from(i));
+                constraint.add_b_variable(5, FieldWrapper::<Fr>::from(1u64));
+                constraint.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+                constraint
+            })
+            .collect();
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 6).unwrap();
+        assert_eq!(interpolated_degree(&a_polys, &b_polys, &c_polys), 2);
+
+        let seed = [42u8; 32];
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        assert_eq!(pk.h_query.len(), 2);
+
+        let prove_and_verify = |values: [i64; 6]| {
+            let witness: Vec<_> = values.into_iter().map(FieldWrapper::<Fr>::from).collect();
+            generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed)
+                .is_ok_and(|proof| matches!(verify_proof(&vk, &proof, &witness[1..2]), Ok(true)))
+        };
+
+        // x₂ = x₄ = 0 and x₃ = 1 satisfy every constraint
+        assert!(prove_and_verify([1, 5, 0, 1, 0, 5]));
+
+        // Satisfies the first two constraints but not the third, which a
+        // t(x) sized from the trimmed polynomials would never check
+        let witness: Vec<_> = [1i64, -2, 1, 0, 1, -3]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+        let satisfied: Vec<_> = constraints
+            .iter()
+            .map(|c| c.is_satisfied(&witness))
+            .collect();
+        assert_eq!(satisfied, [true, true, false]);
+        assert!(!prove_and_verify([1, -2, 1, 0, 1, -3]));
+    }
+
+    #[test]
+    fn test_ic_length_matches_num_inputs() {
+        let mut c1 = R1CSConstraint::<ark_bn254::Fr>::new();
+        c1.add_a_variable(1, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_b_variable(2, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_c_variable(3, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+
+        let constraints = vec![c1.clone(), c1];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+
+        // Every valid num_inputs yields IC = constant + one element per input
+        for num_inputs in 0..a_polys.len() {
+            let seed = [7u8; 32];
+            let (_pk, vk) = trusted_setup_test(
+                &a_polys,
+                &b_polys,
+                &c_polys,
+                constraints.len(),
+                num_inputs,
+                &seed,
+            )
+            .unwrap();
+            assert_eq!(vk.ic.len(), num_inputs + 1);
+        }
+    }
+
+    /// A non-`rand` scalar source replaying a fixed sequence
+    struct SequenceRng(std::vec::IntoIter<u64>);
+
+    impl SetupRng<Fr> for SequenceRng {
+        fn fill_scalar(&mut self) -> Fr {
+            Fr::from(
+                self.0
+                    .next()
+                    .expect("setup drew more scalars than expected"),
+            )
+        }
+    }
+
+    #[test]
+    fn test_custom_setup_rng_gives_reproducible_keys() {
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&[c1.clone(), c1], 4).unwrap();
+
+        // α, β, γ, δ, τ
+        let setup = |scalars: Vec<u64>| {
+            let mut rng = SequenceRng(scalars.into_iter());
+            trusted_setup(&a_polys, &b_polys, &c_polys, 2, 1, &mut rng).unwrap()
+        };
+        let (pk1, vk1) = setup(vec![2, 3, 5, 7, 11]);
+        let (pk2, vk2) = setup(vec![2, 3, 5, 7, 11]);
+
+        assert_eq!(vk1.fingerprint(), vk2.fingerprint());
+        assert_eq!(pk1.a_query, pk2.a_query);
+        assert_eq!(pk1.c_query, pk2.c_query);
+
+        // The scalars come from the custom source, in order
+        let g1 = G1Affine::generator();
+        assert_eq!(pk1.alpha_g1, (g1 * Fr::from(2u64)).into_affine());
+        assert_eq!(pk1.delta_g1, (g1 * Fr::from(7u64)).into_affine());
+
+        let (_, other_vk) = setup(vec![2, 3, 13, 7, 11]);
+        assert_ne!(other_vk.fingerprint(), vk1.fingerprint());
+    }
+
+    #[test]
+    fn test_zero_gamma_or_delta_is_rejected() {
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&squaring_chain(2), 4).unwrap();
+        let setup = |scalars: Vec<u64>| {
+            let mut rng = SequenceRng(scalars.into_iter());
+            trusted_setup(&a_polys, &b_polys, &c_polys, 2, 1, &mut rng)
+        };
+
+        assert!(matches!(
+            setup(vec![2, 3, 0, 7, 11]),
+            Err(Groth16Error::ZeroSetupSecret("γ"))
+        ));
+        assert!(matches!(
+            setup(vec![2, 3, 5, 0, 11]),
+            Err(Groth16Error::ZeroSetupSecret("δ"))
+        ));
+        assert!(setup(vec![2, 3, 5, 7, 11]).is_ok());
+    }
+
+    #[test]
+    fn test_precomputed_tables_match_default_setup() {
+        // Enough constraints to exercise h_query as well
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&squaring_chain(5), 7).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 5, 1, &seed).unwrap();
+
+        // Window sizes that do and do not divide the scalar bit length
+        for window_bits in [1, 5, 8] {
+            let options = SetupOptions {
+                window_bits,
+                use_precomputed_tables: true,
+                ..SetupOptions::default()
+            };
+            let mut rng = ChaCha8Rng::from_seed(seed);
+            let (fast_pk, fast_vk) =
+                trusted_setup_with_options(&a_polys, &b_polys, &c_polys, 5, 1, &options, &mut rng)
+                    .unwrap();
+
+            assert_eq!(fast_pk.alpha_g1, pk.alpha_g1);
+            assert_eq!(fast_pk.beta_g1, pk.beta_g1);
+            assert_eq!(fast_pk.beta_g2, pk.beta_g2);
+            assert_eq!(fast_pk.delta_g1, pk.delta_g1);
+            assert_eq!(fast_pk.delta_g2, pk.delta_g2);
+            assert_eq!(fast_pk.a_query, pk.a_query);
+            assert_eq!(fast_pk.b_g1_query, pk.b_g1_query);
+            assert_eq!(fast_pk.b_g2_query, pk.b_g2_query);
+            assert_eq!(fast_pk.c_query, pk.c_query);
+            assert!(!pk.h_query.is_empty());
+            assert_eq!(fast_pk.h_query, pk.h_query);
+
+            assert_eq!(fast_vk.alpha_g1, vk.alpha_g1);
+            assert_eq!(fast_vk.beta_g2, vk.beta_g2);
+            assert_eq!(fast_vk.gamma_g2, vk.gamma_g2);
+            assert_eq!(fast_vk.delta_g2, vk.delta_g2);
+            assert_eq!(fast_vk.ic, vk.ic);
+        }
+    }
+
+    #[test]
+    fn test_setup_rejects_circuit_above_max_degree() {
+        // 8 copies of x₁·x₁ = x₂: only 3 variables and constant QAP
+        // polynomials, but 8 constraints give a QAP of degree 8
+        let constraints: Vec<_> = std::iter::repeat_n(squaring_chain(1).remove(0), 8).collect();
+
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 3).unwrap();
+        assert_eq!(interpolated_degree(&a_polys, &b_polys, &c_polys), 1);
+        let setup = |max_degree| {
+            let options = SetupOptions {
+                max_degree,
+                ..SetupOptions::default()
+            };
+            let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+            trusted_setup_with_options(
+                &a_polys,
+                &b_polys,
+                &c_polys,
+                constraints.len(),
+                1,
+                &options,
+                &mut rng,
+            )
+        };
+
+        assert!(matches!(
+            setup(7),
+            Err(Groth16Error::CircuitTooLarge { degree: 8, max: 7 })
+        ));
+        assert_eq!(setup(8).unwrap().0.h_query.len(), 7);
+    }
+
+    #[test]
+    fn test_setup_options_reject_bad_window() {
+        let mut c1 = R1CSConstraint::<ark_bn254::Fr>::new();
+        c1.add_a_variable(1, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_b_variable(2, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_c_variable(3, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&[c1.clone(), c1], 4).unwrap();
+        let options = SetupOptions {
+            window_bits: 0,
+            use_precomputed_tables: true,
+            ..SetupOptions::default()
+        };
+        let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+
+        assert!(matches!(
+            trusted_setup_with_options(&a_polys, &b_polys, &c_polys, 2, 1, &options, &mut rng),
+            Err(Groth16Error::InvalidSetupOptions(_))
+        ));
     }
 
     #[test]
@@ -388,8 +842,10 @@ mod tests {
         let seed = [42u8; 32];
 
         // Run setup twice with same seed
-        let (pk1, vk1) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
-        let (pk2, vk2) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk1, vk1) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        let (pk2, vk2) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Should produce identical keys
         assert_eq!(pk1.alpha_g1, pk2.alpha_g1);
@@ -397,6 +853,48 @@ mod tests {
         assert_eq!(vk1.alpha_g1, vk2.alpha_g1);
     }
 
+    #[test]
+    fn test_zeroized_setup_still_proves() {
+        use crate::prove::generate_proof_test;
+        use crate::verify::verify_proof;
+
+        // Multiplier circuit with standard witness ordering [1, c, a, b]
+        let mut c1 = R1CSConstraint::<ark_bn254::Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_b_variable(3, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+        c1.add_c_variable(1, FieldWrapper::<ark_bn254::Fr>::from(1u64));
+
+        let constraints = vec![c1.clone(), c1.clone()];
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
+
+        let seed = [42u8; 32];
+        let (pk1, vk1) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+        let (pk2, vk2) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
+
+        // Zeroizing the secrets must not change the keys derived from them
+        assert_eq!(pk1.a_query, pk2.a_query);
+        assert_eq!(pk1.b_g2_query, pk2.b_g2_query);
+        assert_eq!(pk1.c_query, pk2.c_query);
+        assert_eq!(vk1.gamma_g2, vk2.gamma_g2);
+        assert_eq!(vk1.delta_g2, vk2.delta_g2);
+        assert_eq!(vk1.ic, vk2.ic);
+
+        let witness = vec![
+            FieldWrapper::<ark_bn254::Fr>::from(1u64),
+            FieldWrapper::<ark_bn254::Fr>::from(12u64),
+            FieldWrapper::<ark_bn254::Fr>::from(3u64),
+            FieldWrapper::<ark_bn254::Fr>::from(4u64),
+        ];
+        let proof =
+            generate_proof_test(&pk1, &witness, &a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+
+        // A proof against one key verifies against the other
+        let public_inputs = vec![FieldWrapper::<ark_bn254::Fr>::from(12u64)];
+        assert!(verify_proof(&vk2, &proof, &public_inputs).unwrap());
+    }
+
     #[test]
     fn test_empty_polynomials_error() {
         let a_polys: Vec<Polynomial<ark_bn254::Fr>> = vec![];
@@ -405,7 +903,7 @@ mod tests {
 
         let seed = [42u8; 32];
         let mut rng = ChaCha8Rng::from_seed(seed);
-        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, &mut rng);
+        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, 1, &mut rng);
 
         assert!(result.is_err());
         assert!(matches!(
@@ -429,7 +927,7 @@ mod tests {
 
         let seed = [42u8; 32];
         let mut rng = ChaCha8Rng::from_seed(seed);
-        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, &mut rng);
+        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, 1, &mut rng);
 
         assert!(result.is_err());
         assert!(matches!(
@@ -438,6 +936,18 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_setup_from_r1cs_propagates_qap_error() {
+        // An empty constraint system cannot be turned into a QAP
+        let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+        let result = trusted_setup_from_r1cs::<Fr, _>(&[], 4, 1, &mut rng);
+
+        assert!(matches!(
+            result,
+            Err(Groth16Error::Qap(groth16_qap::QapError::EmptyConstraints))
+        ));
+    }
+
     #[test]
     fn test_invalid_inputs_error() {
         let a_polys = vec![Polynomial::<ark_bn254::Fr>::new(vec![FieldWrapper::<
@@ -453,51 +963,18 @@ mod tests {
         let seed = [42u8; 32];
         let mut rng = ChaCha8Rng::from_seed(seed);
 
-        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 0, &mut rng);
+        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, 0, &mut rng);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
             Groth16Error::InvalidInputs(0)
         ));
 
-        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 2, &mut rng);
+        let result = trusted_setup(&a_polys, &b_polys, &c_polys, 1, 2, &mut rng);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
             Groth16Error::InvalidInputs(2)
         ));
     }
-
-    #[test]
-    fn test_division_polynomials() {
-        let dividend = Polynomial::<ark_bn254::Fr>::new(vec![
-            FieldWrapper::<ark_bn254::Fr>::zero() - FieldWrapper::<ark_bn254::Fr>::one(),
-            FieldWrapper::<ark_bn254::Fr>::zero(),
-            FieldWrapper::<ark_bn254::Fr>::one(),
-        ]);
-
-        let divisor = Polynomial::<ark_bn254::Fr>::new(vec![
-            FieldWrapper::<ark_bn254::Fr>::zero() - FieldWrapper::<ark_bn254::Fr>::one(),
-            FieldWrapper::<ark_bn254::Fr>::one(),
-        ]);
-
-        let (quotient, remainder) = divide_polynomials(&dividend, &divisor).unwrap();
-
-        assert_eq!(quotient.degree(), 1);
-        assert!(remainder.is_zero());
-    }
-
-    #[test]
-    fn test_compute_powers_of_tau() {
-        use ark_ff::One;
-
-        let tau = Fr::one();
-        let powers_g1 = compute_powers_of_tau_g1(tau, 5);
-
-        assert_eq!(powers_g1.len(), 5);
-        let g1_gen = G1Affine::generator();
-        for power in powers_g1 {
-            assert_eq!(power, g1_gen);
-        }
-    }
 }
@@ -1,10 +1,12 @@
 use crate::error::Groth16Error;
-use crate::keys::VerificationKey;
+use crate::keys::{PreparedVerificationKey, VerificationKey};
 use crate::prove::Proof;
-use ark_bn254::{Bn254, Fr, G1Projective as G1};
 use ark_ec::pairing::Pairing;
-use ark_ff::{PrimeField, Zero};
 use groth16_math::fields::FieldWrapper;
+use std::collections::BTreeMap;
+
+/// A proof paired with the public inputs it should verify against
+pub type ProofWithInputs<E> = (Proof<E>, Vec<FieldWrapper<<E as Pairing>::ScalarField>>);
 
 /// Verifies a Groth16 zero-knowledge proof.
 ///
@@ -15,7 +17,7 @@ use groth16_math::fields::FieldWrapper;
 ///
 /// # Returns
 /// * `Ok(true)` - Proof is valid
-/// * `Ok(false)` - Proof is invalid
+/// * `Ok(false)` - Proof is invalid (see [`verify_proof_detailed`] for why)
 /// * `Err(Groth16Error)` - Error during verification
 ///
 /// # Verification Equation
@@ -48,79 +50,203 @@ use groth16_math::fields::FieldWrapper;
 ///
 /// # Example
 /// ```rust,ignore
-/// use groth16_groth16::{verify_proof, generate_proof_test, trusted_setup_test};
+/// use groth16::{verify_proof, generate_proof_test, trusted_setup_test, PublicInput};
 /// use groth16_circuits::multiplier::MultiplierCircuit;
 /// use groth16_qap::r1cs_to_qap;
 ///
 /// // Setup and proof generation
-/// let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed)?;
+/// let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed)?;
 /// let proof = generate_proof_test(&pk, &witness, &a_polys, &b_polys, &c_polys, 1, &seed)?;
 ///
-/// // Verify with public input c=12
-/// let public_inputs = vec![FieldWrapper::<Fr>::from(12u64)];
-/// let is_valid = verify_proof(&vk, &proof, &public_inputs)?;
+/// // Verify with public input c=12; the witness is not needed
+/// let public = PublicInput::new(circuit.public_inputs());
+/// let is_valid = verify_proof(&vk, &proof, public.inputs())?;
 ///
 /// assert!(is_valid);  // Proof should be valid
 /// # Ok::<(), Groth16Error>(())
 /// ```
-pub fn verify_proof(
-    vk: &VerificationKey,
-    proof: &Proof,
-    public_inputs: &[FieldWrapper<Fr>],
+pub fn verify_proof<E: Pairing>(
+    vk: &VerificationKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[FieldWrapper<E::ScalarField>],
 ) -> Result<bool, Groth16Error> {
-    // Validate inputs
-    // The IC vector contains the input consistency elements
-    // Different implementations handle the constant differently:
-    // - Some include IC[0] for constant 1, then IC[1..] for public inputs
-    // - Others only include IC for public inputs (constant is implicit)
-    // We support both cases by checking the length
-    if vk.ic.is_empty() && !public_inputs.is_empty() {
-        return Err(Groth16Error::InvalidInputs(public_inputs.len()));
+    let result = traced!("groth16::verify", {
+        num_public_inputs = public_inputs.len(),
+    }, {
+        verify_detailed_inner(vk, proof, public_inputs, false)
+    })?;
+    Ok(matches!(result, VerificationResult::Accepted))
+}
+
+/// Verifies a Groth16 proof against public inputs given by position.
+///
+/// Each key is the IC index of the input, i.e. its witness index in the
+/// [1, public_inputs..., private_inputs...] layout, so valid keys are
+/// `1..vk.ic.len()`. Indices without an entry are treated as zero, and
+/// the map order does not matter.
+///
+/// # Returns
+/// * `Ok(true)` / `Ok(false)` - As for [`verify_proof`] on the ordered inputs
+/// * `Err(Groth16Error::InvalidPublicInputIndex)` - If a key is 0 (the
+///   constant) or not below `vk.ic.len()`
+pub fn verify_proof_labeled<E: Pairing>(
+    vk: &VerificationKey<E>,
+    proof: &Proof<E>,
+    inputs: &BTreeMap<usize, FieldWrapper<E::ScalarField>>,
+) -> Result<bool, Groth16Error> {
+    let num_inputs = vk.ic.len().saturating_sub(1);
+    let mut ordered = vec![FieldWrapper::<E::ScalarField>::zero(); num_inputs];
+
+    for (&index, value) in inputs {
+        if index == 0 || index > num_inputs {
+            return Err(Groth16Error::InvalidPublicInputIndex { index, num_inputs });
+        }
+        ordered[index - 1] = value.clone();
     }
 
-    // Step 1: Compute left side of verification equation
-    // Left side: e(A, B)
-    let left = Bn254::pairing(proof.a, proof.b);
+    verify_proof(vk, proof, &ordered)
+}
 
-    // Step 2: Compute right side components
-    // Component 1: e(α, β)
-    let alpha_beta = Bn254::pairing(vk.alpha_g1, vk.beta_g2);
+/// Verifies a proof against a [`PreparedVerificationKey`].
+///
+/// Accepts exactly the proofs [`verify_proof`] accepts, but reuses the
+/// precomputed e(α, β) and prepared γ, δ, and evaluates the remaining
+/// pairings with one shared final exponentiation. Prefer it when
+/// verifying many proofs against the same key.
+///
+/// # Returns
+/// * `Ok(true)` - Proof is valid
+/// * `Ok(false)` - Proof is invalid, including a wrong number of public inputs
+pub fn verify_proof_prepared<E: Pairing>(
+    pvk: &PreparedVerificationKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[FieldWrapper<E::ScalarField>],
+) -> Result<bool, Groth16Error> {
+    let vk = &pvk.vk;
+    if vk.ic.len() != public_inputs.len() + 1 {
+        return Ok(false);
+    }
 
-    // Component 2: e(Σpublic_i·IC_i, γ)
-    // Compute the linear combination of IC elements with public inputs
-    // Check if IC includes the constant (IC length = public_inputs + 1)
-    // or just public inputs (IC length = public_inputs)
-    let has_constant = vk.ic.len() == public_inputs.len() + 1;
+    let mut public_acc = E::G1::from(vk.ic[0]);
+    for (input, ic_point) in public_inputs.iter().zip(&vk.ic[1..]) {
+        public_acc += E::G1::from(*ic_point) * input.value;
+    }
 
-    let mut public_acc = G1::zero();
+    let left = E::pairing(proof.a, proof.b);
+    let public_and_c = E::multi_pairing(
+        [
+            E::G1Prepared::from(public_acc),
+            E::G1Prepared::from(proof.c),
+        ],
+        [pvk.gamma_g2_prepared.clone(), pvk.delta_g2_prepared.clone()],
+    );
 
-    if has_constant {
-        // IC[0] is for constant 1, IC[1..] are for public inputs
-        if !vk.ic.is_empty() {
-            public_acc += G1::from(vk.ic[0]);
-        }
-        for (i, input) in public_inputs.iter().enumerate() {
-            if i + 1 < vk.ic.len() {
-                let input_scalar = input.value;
-                let ic_point = G1::from(vk.ic[i + 1]);
-                public_acc += ic_point * input_scalar;
-            }
-        }
-    } else {
-        // IC only contains public inputs, constant is implicit
-        for (i, input) in public_inputs.iter().enumerate() {
-            if i < vk.ic.len() {
-                let input_scalar = input.value;
-                let ic_point = G1::from(vk.ic[i]);
-                public_acc += ic_point * input_scalar;
-            }
-        }
+    Ok(left.0 == pvk.alpha_beta.0 * public_and_c.0)
+}
+
+/// Why a proof was rejected by [`verify_proof_detailed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectReason {
+    /// The number of public inputs does not match the verification key's IC
+    PublicInputCountMismatch { expected: usize, actual: usize },
+    /// The pairing equation e(A, B) = e(α, β) · e(Σpublic·IC, γ) · e(C, δ) does not hold
+    PairingMismatch,
+    /// The pairing equation holds once the public input at `index` is zeroed,
+    /// so that input is the likely culprit (heuristic, see [`verify_proof_detailed`])
+    PublicInputMismatch { index: usize },
+}
+
+/// Outcome of verifying a proof with [`verify_proof_detailed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationResult {
+    /// The proof is valid for the given public inputs
+    Accepted,
+    /// The proof was checked and is not valid
+    Rejected { reason: RejectReason },
+}
+
+/// Verifies a Groth16 proof, reporting why it was rejected.
+///
+/// Performs the same checks as [`verify_proof`], but distinguishes
+/// "verification ran and rejected the proof" from errors, and records which
+/// check failed so callers can log it.
+///
+/// # Diagnosing Public Inputs
+/// When the pairing check fails, each public input is zeroed in turn and the
+/// equation re-checked (one extra pairing per input). If it holds with input
+/// `i` zeroed, the proof is rejected with
+/// `RejectReason::PublicInputMismatch { index: i }`. This is a debugging
+/// heuristic that only identifies an input whose correct value is 0, supplied
+/// as non-zero (e.g. a flag or an unused slot). Any other wrong input falls
+/// back to `RejectReason::PairingMismatch`.
+///
+/// The extra pairings make rejections up to n + 1 times as expensive, so
+/// only this function runs the diagnosis; [`verify_proof`] and
+/// [`batch_verify`] stop at the first failed check.
+///
+/// # Returns
+/// * `Ok(VerificationResult::Accepted)` - Proof is valid
+/// * `Ok(VerificationResult::Rejected { reason })` - Proof is invalid
+/// * `Err(Groth16Error)` - Error during verification
+pub fn verify_proof_detailed<E: Pairing>(
+    vk: &VerificationKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[FieldWrapper<E::ScalarField>],
+) -> Result<VerificationResult, Groth16Error> {
+    traced!("groth16::verify", {
+        num_public_inputs = public_inputs.len(),
+    }, {
+        verify_detailed_inner(vk, proof, public_inputs, true)
+    })
+}
+
+/// The body of [`verify_proof`] and [`verify_proof_detailed`], running the
+/// public input diagnosis only when `diagnose` is set
+fn verify_detailed_inner<E: Pairing>(
+    vk: &VerificationKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[FieldWrapper<E::ScalarField>],
+    diagnose: bool,
+) -> Result<VerificationResult, Groth16Error> {
+    // Validate inputs
+    // trusted_setup guarantees IC[0] for the constant 1 followed by one
+    // element per public input, so the lengths must line up exactly
+    if vk.ic.len() != public_inputs.len() + 1 {
+        return Ok(VerificationResult::Rejected {
+            reason: RejectReason::PublicInputCountMismatch {
+                expected: vk.ic.len().saturating_sub(1),
+                actual: public_inputs.len(),
+            },
+        });
     }
 
-    let public_gamma = Bn254::pairing(public_acc, vk.gamma_g2);
+    // Compute the linear combination of IC elements with public inputs
+    // IC[0] is for constant 1, IC[1..] are for public inputs
+    let public_acc = traced!("public_input_msm", {
+        let mut public_acc = E::G1::from(vk.ic[0]);
+        for (input, ic_point) in public_inputs.iter().zip(&vk.ic[1..]) {
+            public_acc += E::G1::from(*ic_point) * input.value;
+        }
+        public_acc
+    });
+
+    let (left, alpha_beta, public_gamma, c_delta) = traced!("pairings", {
+        // Step 1: Compute left side of verification equation
+        // Left side: e(A, B)
+        let left = E::pairing(proof.a, proof.b);
 
-    // Component 3: e(C, δ)
-    let c_delta = Bn254::pairing(proof.c, vk.delta_g2);
+        // Step 2: Compute right side components
+        // Component 1: e(α, β)
+        let alpha_beta = E::pairing(vk.alpha_g1, vk.beta_g2);
+
+        // Component 2: e(Σpublic_i·IC_i, γ)
+        let public_gamma = E::pairing(public_acc, vk.gamma_g2);
+
+        // Component 3: e(C, δ)
+        let c_delta = E::pairing(proof.c, vk.delta_g2);
+
+        (left, alpha_beta, public_gamma, c_delta)
+    });
 
     // Right side: e(α, β) · e(Σpublic·IC, γ) · e(C, δ)
     // PairingOutput is a newtype wrapper, so we access .0 to get the TargetField
@@ -129,32 +255,68 @@ pub fn verify_proof(
     // Step 3: Check if verification equation holds
     let is_valid = left.0 == right_field;
 
-    // Debug output
+    // Only recorded with the `tracing` feature and a debug-level subscriber
+    #[cfg(feature = "tracing")]
     if !is_valid {
-        eprintln!("Verification FAILED:");
-        eprintln!("  left (pairing e(A,B)): {:?}", left.0);
-        eprintln!("  right (e(α,β) * e(public,γ) * e(C,δ)): {:?}", right_field);
-        eprintln!("  e(α,β): {:?}", alpha_beta.0);
-        eprintln!("  e(public,γ): {:?}", public_gamma.0);
-        eprintln!("  e(C,δ): {:?}", c_delta.0);
-        eprintln!(
-            "  IC length: {}, public_inputs length: {}",
-            vk.ic.len(),
-            public_inputs.len()
-        );
-        eprintln!("  public_inputs: {:?}", public_inputs);
-        // Check if the issue is with the left or right side
-        eprintln!(
-            "  alpha-beta * public-gamma: {:?}",
-            (alpha_beta.0 * public_gamma.0)
-        );
-        eprintln!(
-            "  (alpha-beta * public-gamma) * c-delta: {:?}",
-            ((alpha_beta.0 * public_gamma.0) * c_delta.0)
+        tracing::debug!(
+            left = ?left.0,
+            right = ?right_field,
+            alpha_beta = ?alpha_beta.0,
+            public_gamma = ?public_gamma.0,
+            c_delta = ?c_delta.0,
+            public_inputs = ?public_inputs,
+            "pairing check failed"
         );
     }
 
-    Ok(is_valid)
+    if is_valid {
+        return Ok(VerificationResult::Accepted);
+    }
+    if !diagnose {
+        return Ok(VerificationResult::Rejected {
+            reason: RejectReason::PairingMismatch,
+        });
+    }
+
+    // Heuristic diagnosis: zero each public input in turn and re-check
+    let fixed = alpha_beta.0 * c_delta.0;
+    for (index, (input, ic_point)) in public_inputs.iter().zip(&vk.ic[1..]).enumerate() {
+        let candidate = public_acc - E::G1::from(*ic_point) * input.value;
+        if left.0 == fixed * E::pairing(candidate, vk.gamma_g2).0 {
+            return Ok(VerificationResult::Rejected {
+                reason: RejectReason::PublicInputMismatch { index },
+            });
+        }
+    }
+
+    Ok(VerificationResult::Rejected {
+        reason: RejectReason::PairingMismatch,
+    })
+}
+
+/// Estimated cost of verifying a single proof against a verification key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerificationCost {
+    /// Number of pairing evaluations
+    pub pairings: usize,
+    /// Number of G₁ scalar multiplications (one per public input)
+    pub g1_scalar_muls: usize,
+}
+
+/// Estimates the cost of running [`verify_proof`] against the given key.
+///
+/// The pairing count is constant: e(A, B), e(α, β), e(Σpublic_i·IC_i, γ)
+/// and e(C, δ). The only circuit-dependent work is the linear combination
+/// of IC elements, which costs one G₁ scalar multiplication per public input
+/// (IC[0] for the constant 1 is added without a multiplication).
+///
+/// This is informational and intended for budgeting (e.g. on-chain gas
+/// estimates or batching thresholds).
+pub fn verification_cost<E: Pairing>(vk: &VerificationKey<E>) -> VerificationCost {
+    VerificationCost {
+        pairings: 4,
+        g1_scalar_muls: vk.ic.len().saturating_sub(1),
+    }
 }
 
 /// Batch verifies multiple Groth16 proofs efficiently using random linear combination.
@@ -286,12 +448,13 @@ pub fn verify_proof(
 /// - Verifying a single proof
 /// - Proofs use different circuits (different vk)
 /// - You need to identify which specific proof failed
-pub fn batch_verify<R>(
-    vk: &VerificationKey,
-    proofs_and_inputs: &[(Proof, Vec<FieldWrapper<Fr>>)],
+pub fn batch_verify<E, R>(
+    vk: &VerificationKey<E>,
+    proofs_and_inputs: &[ProofWithInputs<E>],
     _rng: &mut R,
 ) -> Result<bool, Groth16Error>
 where
+    E: Pairing,
     R: rand::RngCore + rand::CryptoRng,
 {
     if proofs_and_inputs.is_empty() {
@@ -315,6 +478,7 @@ mod tests {
     use super::*;
     use crate::prove::generate_proof_test;
     use crate::setup::trusted_setup_test;
+    use ark_bn254::{Bn254, Fr};
     use groth16_qap::r1cs_to_qap;
     use groth16_r1cs::constraint::R1CSConstraint;
     use rand::SeedableRng;
@@ -334,7 +498,8 @@ mod tests {
 
         // Setup
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Generate witness for a=3, b=4, c=12
         // Witness ordering: [1, c, a, b]
@@ -368,7 +533,8 @@ mod tests {
         let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
 
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Proof for a=3, b=4, c=12
         let witness = vec![
@@ -388,6 +554,209 @@ mod tests {
         assert!(!is_valid, "Proof should NOT verify with wrong public input");
     }
 
+    #[test]
+    fn test_verify_detailed_wrong_public_input() {
+        let (pk, vk, constraints) = setup_test_circuit();
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(12u64),
+            FieldWrapper::<Fr>::from(3u64),
+            FieldWrapper::<Fr>::from(4u64),
+        ];
+        let proof = generate_proof_for_witness(&pk, &witness, &constraints);
+
+        let public_inputs = vec![FieldWrapper::<Fr>::from(99u64)];
+        let result = verify_proof_detailed(&vk, &proof, &public_inputs).unwrap();
+
+        assert_eq!(
+            result,
+            VerificationResult::Rejected {
+                reason: RejectReason::PairingMismatch
+            }
+        );
+    }
+
+    /// Builds a two-public-input key and a proof that is valid for `inputs`.
+    ///
+    /// Uses β = γ = δ = G₂ and picks A so that
+    /// e(A, G₂) = e(α, G₂) · e(Σpublic·IC, G₂) · e(C, G₂) holds by construction.
+    fn synthetic_key_and_proof<E: Pairing>(inputs: [u64; 2]) -> (VerificationKey<E>, Proof<E>) {
+        use ark_ec::{AffineRepr, CurveGroup};
+
+        let g1 = E::G1Affine::generator();
+        let g2 = E::G2Affine::generator();
+        let times = |k: u64| (g1 * E::ScalarField::from(k)).into_affine();
+
+        let vk = VerificationKey {
+            alpha_g1: times(3),
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            ic: [5u64, 7, 11].into_iter().map(times).collect(),
+        };
+
+        let acc = 5 + 7 * inputs[0] + 11 * inputs[1];
+        let proof = Proof {
+            a: times(3 + acc + 2),
+            b: g2,
+            c: times(2),
+        };
+
+        (vk, proof)
+    }
+
+    /// Checks the synthetic proof verifies on curve `E` and rejects a tampered input
+    fn check_synthetic_proof_on<E: Pairing>() {
+        let (vk, proof) = synthetic_key_and_proof::<E>([4, 9]);
+        let inputs = [4u64, 9].map(FieldWrapper::<E::ScalarField>::from);
+        assert!(verify_proof(&vk, &proof, &inputs).unwrap());
+
+        let tampered = [4u64, 10].map(FieldWrapper::<E::ScalarField>::from);
+        assert!(!verify_proof(&vk, &proof, &tampered).unwrap());
+    }
+
+    #[test]
+    fn test_verify_on_bn254_and_bls12_381() {
+        check_synthetic_proof_on::<ark_bn254::Bn254>();
+        check_synthetic_proof_on::<ark_bls12_381::Bls12_381>();
+    }
+
+    #[test]
+    fn test_verify_detailed_reports_public_input_index() {
+        // The proof expects [4, 0]. The heuristic only finds an input whose
+        // correct value is 0, so the wrong input is the second one
+        let (vk, proof) = synthetic_key_and_proof::<Bn254>([4, 0]);
+
+        let correct = vec![
+            FieldWrapper::<Fr>::from(4u64),
+            FieldWrapper::<Fr>::from(0u64),
+        ];
+        assert_eq!(
+            verify_proof_detailed(&vk, &proof, &correct).unwrap(),
+            VerificationResult::Accepted
+        );
+
+        // Second input is wrong: zeroing it restores the expected value
+        let wrong_second = vec![
+            FieldWrapper::<Fr>::from(4u64),
+            FieldWrapper::<Fr>::from(9u64),
+        ];
+        assert_eq!(
+            verify_proof_detailed(&vk, &proof, &wrong_second).unwrap(),
+            VerificationResult::Rejected {
+                reason: RejectReason::PublicInputMismatch { index: 1 }
+            }
+        );
+        assert!(!verify_proof(&vk, &proof, &wrong_second).unwrap());
+
+        // The first input should be 4, not 0, so zeroing no single input
+        // repairs this one
+        let wrong_first = vec![
+            FieldWrapper::<Fr>::from(6u64),
+            FieldWrapper::<Fr>::from(0u64),
+        ];
+        assert_eq!(
+            verify_proof_detailed(&vk, &proof, &wrong_first).unwrap(),
+            VerificationResult::Rejected {
+                reason: RejectReason::PairingMismatch
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_labeled_matches_ordered() {
+        // The proof expects [4, 0]
+        let (vk, proof) = synthetic_key_and_proof::<Bn254>([4, 0]);
+
+        // Index 2 is left out and filled with zero
+        let labeled = BTreeMap::from([(1, FieldWrapper::<Fr>::from(4u64))]);
+        let ordered = vec![
+            FieldWrapper::<Fr>::from(4u64),
+            FieldWrapper::<Fr>::from(0u64),
+        ];
+        assert!(verify_proof_labeled(&vk, &proof, &labeled).unwrap());
+        assert_eq!(
+            verify_proof_labeled(&vk, &proof, &labeled).unwrap(),
+            verify_proof(&vk, &proof, &ordered).unwrap()
+        );
+
+        // Inserted out of order, mapped by index
+        let labeled = BTreeMap::from([
+            (2, FieldWrapper::<Fr>::from(9u64)),
+            (1, FieldWrapper::<Fr>::from(4u64)),
+        ]);
+        let ordered = vec![
+            FieldWrapper::<Fr>::from(4u64),
+            FieldWrapper::<Fr>::from(9u64),
+        ];
+        assert!(!verify_proof_labeled(&vk, &proof, &labeled).unwrap());
+        assert_eq!(
+            verify_proof_labeled(&vk, &proof, &labeled).unwrap(),
+            verify_proof(&vk, &proof, &ordered).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_labeled_rejects_bad_index() {
+        let (vk, proof) = synthetic_key_and_proof::<Bn254>([4, 0]);
+
+        for index in [0, 3] {
+            let labeled = BTreeMap::from([(index, FieldWrapper::<Fr>::from(1u64))]);
+            assert!(matches!(
+                verify_proof_labeled(&vk, &proof, &labeled),
+                Err(Groth16Error::InvalidPublicInputIndex { num_inputs: 2, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_detailed_input_count_mismatch() {
+        let (pk, vk, constraints) = setup_test_circuit();
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(12u64),
+            FieldWrapper::<Fr>::from(3u64),
+            FieldWrapper::<Fr>::from(4u64),
+        ];
+        let proof = generate_proof_for_witness(&pk, &witness, &constraints);
+
+        let public_inputs = vec![
+            FieldWrapper::<Fr>::from(12u64),
+            FieldWrapper::<Fr>::from(3u64),
+        ];
+        let result = verify_proof_detailed(&vk, &proof, &public_inputs).unwrap();
+
+        assert_eq!(
+            result,
+            VerificationResult::Rejected {
+                reason: RejectReason::PublicInputCountMismatch {
+                    expected: 1,
+                    actual: 2
+                }
+            }
+        );
+        assert!(!verify_proof(&vk, &proof, &public_inputs).unwrap());
+    }
+
+    #[test]
+    fn test_verify_detailed_empty_ic_is_rejected() {
+        // A malformed key without even IC[0] must not underflow
+        let (mut vk, proof) = synthetic_key_and_proof::<Bn254>([4, 0]);
+        vk.ic = vec![];
+
+        for inputs in [vec![], vec![FieldWrapper::<Fr>::from(4u64)]] {
+            assert_eq!(
+                verify_proof_detailed(&vk, &proof, &inputs).unwrap(),
+                VerificationResult::Rejected {
+                    reason: RejectReason::PublicInputCountMismatch {
+                        expected: 0,
+                        actual: inputs.len()
+                    }
+                }
+            );
+        }
+    }
+
     #[test]
     fn test_verify_with_empty_public_inputs() {
         // Create a circuit with no public inputs (except constant 1)
@@ -402,7 +771,8 @@ mod tests {
 
         // Setup with 0 public inputs (only constant 1)
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 0, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 0, &seed).unwrap();
 
         // Generate witness (all values are private in this case)
         let witness = vec![
@@ -437,7 +807,8 @@ mod tests {
         let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
 
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Generate multiple valid proofs
         // Proof 1: 3 × 4 = 12
@@ -496,7 +867,8 @@ mod tests {
         let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, 4).unwrap();
 
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         // Generate one valid proof: 3 × 4 = 12
         let proof1 = generate_proof_test(
@@ -560,7 +932,8 @@ mod tests {
 
         // Setup with 1 public input (c)
         let seed = [42u8; 32];
-        let (pk, vk) = trusted_setup_test(&a_polys, &b_polys, &c_polys, 1, &seed).unwrap();
+        let (pk, vk) =
+            trusted_setup_test(&a_polys, &b_polys, &c_polys, constraints.len(), 1, &seed).unwrap();
 
         (pk, vk, constraints)
     }
@@ -713,6 +1086,26 @@ mod tests {
         assert!(result, "Single valid proof should verify in batch");
     }
 
+    #[test]
+    fn test_verification_cost_single_public_input() {
+        let (pk, vk, constraints) = setup_test_circuit();
+
+        let cost = verification_cost(&vk);
+        assert_eq!(cost.pairings, 4);
+        assert_eq!(cost.g1_scalar_muls, 1);
+
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),  // constant 1
+            FieldWrapper::<Fr>::from(12u64), // c
+            FieldWrapper::<Fr>::from(3u64),  // a
+            FieldWrapper::<Fr>::from(4u64),  // b
+        ];
+        let proof = generate_proof_for_witness(&pk, &witness, &constraints);
+
+        // Two compressed G₁ points (32 bytes) and one compressed G₂ point (64 bytes)
+        assert_eq!(proof.size_bytes(), 128);
+    }
+
     #[test]
     fn test_batch_verify_empty() {
         let (_pk, vk, _constraints) = setup_test_circuit();
@@ -724,4 +1117,62 @@ mod tests {
         let result = batch_verify(&vk, &proofs_and_inputs, &mut rng).unwrap();
         assert!(result, "Empty batch should be valid");
     }
+
+    #[test]
+    fn test_prepared_verification_matches_standard() {
+        let (vk, proof) = synthetic_key_and_proof::<Bn254>([4, 9]);
+        let pvk = vk.clone().prepare();
+
+        let candidates = [
+            vec![
+                FieldWrapper::<Fr>::from(4u64),
+                FieldWrapper::<Fr>::from(9u64),
+            ],
+            vec![
+                FieldWrapper::<Fr>::from(4u64),
+                FieldWrapper::<Fr>::from(8u64),
+            ],
+            vec![
+                FieldWrapper::<Fr>::from(0u64),
+                FieldWrapper::<Fr>::from(0u64),
+            ],
+            vec![FieldWrapper::<Fr>::from(4u64)],
+        ];
+        for inputs in &candidates {
+            assert_eq!(
+                verify_proof_prepared(&pvk, &proof, inputs).unwrap(),
+                verify_proof(&vk, &proof, inputs).unwrap()
+            );
+        }
+        assert!(verify_proof_prepared(&pvk, &proof, &candidates[0]).unwrap());
+    }
+
+    #[test]
+    fn test_rerandomized_proof_still_verifies() {
+        // A real key, so that β, γ and δ are independent
+        let (pk, vk, constraints) = setup_test_circuit();
+        let witness: Vec<_> = [1u64, 12, 3, 4]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+        let proof = generate_proof_for_witness(&pk, &witness, &constraints);
+        let inputs = vec![FieldWrapper::<Fr>::from(12u64)];
+        assert!(verify_proof(&vk, &proof, &inputs).unwrap());
+        let mut rng = ChaCha8Rng::from_seed([5u8; 32]);
+
+        let mut previous = proof.clone();
+        for _ in 0..4 {
+            let rerandomized = proof.rerandomize(&vk, &mut rng);
+            assert_ne!(rerandomized, proof);
+            assert_ne!(rerandomized, previous);
+            assert_ne!(rerandomized.id(), proof.id());
+            assert_ne!(rerandomized.id(), previous.id());
+            assert!(verify_proof(&vk, &rerandomized, &inputs).unwrap());
+
+            // Re-randomizing does not make a proof valid for other inputs
+            let wrong = vec![FieldWrapper::<Fr>::from(13u64)];
+            assert!(!verify_proof(&vk, &rerandomized, &wrong).unwrap());
+            previous = rerandomized;
+        }
+    }
 }
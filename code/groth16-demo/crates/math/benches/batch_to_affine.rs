@@ -0,0 +1,35 @@
+//! Benchmarks for projective-to-affine conversion
+//!
+//! Run with: cargo bench --package groth16-math --bench batch_to_affine
+//!
+//! Compares converting 1000 G1 points one at a time with `into_affine`
+//! (one field inversion each) against `batch_to_affine` (one inversion
+//! shared by the whole batch).
+
+use ark_bn254::{Fr, G1Affine, G1Projective};
+use ark_ec::{AffineRepr, CurveGroup};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use groth16_math::pairing::batch_to_affine;
+
+fn bench_to_affine(c: &mut Criterion) {
+    let g = G1Affine::generator();
+    let points: Vec<G1Projective> = (1..=1000u64).map(|k| g * Fr::from(k)).collect();
+
+    let mut group = c.benchmark_group("to_affine_1000");
+
+    group.bench_function("into_affine", |b| {
+        b.iter(|| {
+            let affine: Vec<G1Affine> = points.iter().map(|p| p.into_affine()).collect();
+            black_box(affine);
+        });
+    });
+
+    group.bench_function("batch_to_affine", |b| {
+        b.iter(|| black_box(batch_to_affine(&points)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_affine);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Benchmarks for multi-scalar multiplication
+//!
+//! Run with: cargo bench --package groth16-math --bench msm
+//!
+//! Compares summing 4096 scalar multiplications one by one against
+//! `msm` (Pippenger). Add `--features parallel` to spread `msm` over
+//! all cores.
+
+use ark_bn254::{Fr, G1Affine, G1Projective};
+use ark_ec::CurveGroup;
+use ark_ff::{UniformRand, Zero};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use groth16_math::pairing::msm;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn bench_msm(c: &mut Criterion) {
+    let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+    let bases: Vec<G1Affine> = (0..4096)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect();
+    let scalars: Vec<Fr> = (0..4096).map(|_| Fr::rand(&mut rng)).collect();
+
+    let mut group = c.benchmark_group("msm_4096");
+    group.sample_size(10);
+
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            let sum = bases
+                .iter()
+                .zip(&scalars)
+                .fold(G1Projective::zero(), |acc, (base, scalar)| {
+                    acc + *base * scalar
+                });
+            let _ = black_box(sum);
+        });
+    });
+
+    group.bench_function("msm", |b| {
+        b.iter(|| black_box(msm::<G1Projective>(&bases, &scalars)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_msm);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+use crate::pairing::ct_scalar_mul;
+use ark_bn254::{Fq, Fr, G1Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+
+/// Domain separator used to derive the blinding generator H
+const PEDERSEN_H_DOMAIN: &[u8] = b"groth16-demo/pedersen/H/v1";
+
+/// Returns the generators (G, H) used by [`pedersen_commit`].
+///
+/// G is the standard BN254 G₁ generator. H is derived by hashing a fixed
+/// domain separator to an x-coordinate (try-and-increment), so nobody knows
+/// the discrete log of H with respect to G. BN254 G₁ has cofactor 1, so any
+/// point on the curve is in the prime-order subgroup.
+pub fn pedersen_generators() -> (G1Affine, G1Affine) {
+    static H: OnceLock<G1Affine> = OnceLock::new();
+    let h = *H.get_or_init(|| {
+        (0u32..)
+            .find_map(|counter| {
+                let digest = Sha256::new()
+                    .chain_update(PEDERSEN_H_DOMAIN)
+                    .chain_update(counter.to_le_bytes())
+                    .finalize();
+                let x = Fq::from_le_bytes_mod_order(&digest);
+                G1Affine::get_point_from_x_unchecked(x, false)
+            })
+            .expect("a valid x-coordinate is found within a few attempts")
+    });
+    (G1Affine::generator(), h)
+}
+
+/// Commits to `value` with randomness `blinding`: value·G + blinding·H.
+///
+/// The commitment is perfectly hiding (a uniform `blinding` makes it a
+/// uniform point) and computationally binding (opening it to another value
+/// means finding log_G(H)). Both multiplications use [`ct_scalar_mul`], since
+/// the value and the blinding are secrets.
+///
+/// # Example
+/// ```
+/// use groth16_math::commitment::{pedersen_commit, pedersen_open};
+/// use ark_bn254::Fr;
+///
+/// let commitment = pedersen_commit(Fr::from(25u64), Fr::from(7u64));
+/// assert!(pedersen_open(&commitment, Fr::from(25u64), Fr::from(7u64)));
+/// assert!(!pedersen_open(&commitment, Fr::from(26u64), Fr::from(7u64)));
+/// ```
+pub fn pedersen_commit(value: Fr, blinding: Fr) -> G1Affine {
+    let (g, h) = pedersen_generators();
+    (ct_scalar_mul(g, value) + ct_scalar_mul(h, blinding)).into_affine()
+}
+
+/// Checks that `commitment` opens to `value` with `blinding`.
+pub fn pedersen_open(commitment: &G1Affine, value: Fr, blinding: Fr) -> bool {
+    pedersen_commit(value, blinding) == *commitment
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::commitment::{pedersen_commit, pedersen_generators, pedersen_open};
+    use ark_bn254::{Fr, G1Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::UniformRand;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_generators_are_independent_curve_points() {
+        let (g, h) = pedersen_generators();
+        assert_eq!(g, G1Affine::generator());
+        assert!(h.is_on_curve());
+        assert!(!h.is_zero());
+        assert_ne!(g, h);
+
+        // Derivation is deterministic
+        assert_eq!(pedersen_generators().1, h);
+    }
+
+    #[test]
+    fn test_commitment_is_homomorphic() {
+        let mut rng = ChaCha8Rng::from_seed([42u8; 32]);
+        let (v1, r1) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        let (v2, r2) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+
+        let sum = (pedersen_commit(v1, r1) + pedersen_commit(v2, r2)).into_affine();
+        assert_eq!(sum, pedersen_commit(v1 + v2, r1 + r2));
+    }
+
+    #[test]
+    fn test_open_rejects_inconsistent_value() {
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        let value = Fr::from(25u64);
+        let blinding = Fr::rand(&mut rng);
+        let commitment = pedersen_commit(value, blinding);
+
+        assert!(pedersen_open(&commitment, value, blinding));
+        assert!(!pedersen_open(&commitment, Fr::from(24u64), blinding));
+        assert!(!pedersen_open(
+            &commitment,
+            value,
+            blinding + Fr::from(1u64)
+        ));
+    }
+}
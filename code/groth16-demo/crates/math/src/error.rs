@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Errors that can occur when constructing field elements from external data
+#[derive(Error, Debug)]
+pub enum FieldError {
+    #[error("Invalid decimal integer: {0:?}")]
+    InvalidDecimal(String),
+
+    #[error("Value is not below the field modulus: {0}")]
+    OutOfRange(String),
+}
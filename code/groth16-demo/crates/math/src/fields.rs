@@ -1,8 +1,22 @@
-use ark_ff::PrimeField;
-use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+use ark_ff::{BigInt, BigInteger, PrimeField};
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use subtle::{Choice, ConditionallySelectable};
+
+/// Order of the BN254 base field Fq, in decimal
+pub const BN254_FQ_MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// Order of the BN254 scalar field Fr, in decimal
+pub const BN254_FR_MODULUS: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
 
 /// Wrapper around arkworks field elements for type safety
-#[derive(Clone, Debug, Serialize, Deserialize)]
+///
+/// Serializes as the canonical big-endian bytes of the element, hex-encoded
+/// (e.g. `"00…0c"` for 12), so witnesses can be stored as stable JSON.
+#[derive(Clone, Debug)]
 pub struct FieldWrapper<F: PrimeField> {
     pub value: F,
 }
@@ -21,6 +35,106 @@ impl<F: PrimeField> FieldWrapper<F> {
     pub fn one() -> Self {
         Self { value: F::one() }
     }
+
+    /// Returns `a` if `cond` is true and `b` otherwise, without branching.
+    ///
+    /// Each limb of the canonical representation is picked with
+    /// `subtle`'s `ConditionallySelectable`, so the sequence of operations
+    /// does not depend on `cond` or on the values. Use it when computing
+    /// witnesses for conditional circuits, where an `if` on a secret would
+    /// leak it through the prover's timing.
+    pub fn select(cond: bool, a: &Self, b: &Self) -> Self {
+        let choice = Choice::from(cond as u8);
+        let a_int = a.value.into_bigint();
+        let mut selected = b.value.into_bigint();
+        for (limb, a_limb) in selected.as_mut().iter_mut().zip(a_int.as_ref()) {
+            *limb = u64::conditional_select(limb, a_limb, choice);
+        }
+        Self {
+            value: F::from_bigint(selected).expect("both inputs are canonical field elements"),
+        }
+    }
+
+    /// Returns the field modulus (its characteristic and order).
+    ///
+    /// Useful for range-checking externally supplied integers before
+    /// wrapping them, since `from` silently reduces modulo this value.
+    pub fn modulus() -> BigUint {
+        F::MODULUS.into()
+    }
+
+    /// Parses a non-negative decimal integer, rejecting values ≥ the modulus.
+    ///
+    /// Unlike `from`, which reduces silently, this is meant for untrusted
+    /// input such as public inputs given on a command line.
+    pub fn from_decimal_str(s: &str) -> Result<Self, FieldError> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FieldError::InvalidDecimal(s.to_string()));
+        }
+        let int: BigUint = s
+            .parse()
+            .map_err(|_| FieldError::InvalidDecimal(s.to_string()))?;
+        Self::from_biguint_checked(int)
+    }
+
+    /// Interprets big-endian bytes as an integer, rejecting values ≥ the modulus.
+    ///
+    /// Any length is accepted as long as the value fits; leading zero bytes
+    /// are allowed.
+    pub fn from_be_bytes_checked(bytes: &[u8]) -> Result<Self, FieldError> {
+        Self::from_biguint_checked(BigUint::from_bytes_be(bytes))
+    }
+
+    fn from_biguint_checked(int: BigUint) -> Result<Self, FieldError> {
+        if int >= Self::modulus() {
+            return Err(FieldError::OutOfRange(int.to_string()));
+        }
+        Ok(Self {
+            value: F::from(int),
+        })
+    }
+}
+
+impl<F: PrimeField<BigInt = BigInt<4>>> FieldWrapper<F> {
+    /// Builds an element from its canonical little-endian 64-bit limbs.
+    ///
+    /// This is the integer representation (not Montgomery form), matching
+    /// [`to_u64_limbs`](Self::to_u64_limbs). Limbs encoding a value at or
+    /// above the modulus are reduced.
+    pub fn from_u64_limbs(limbs: [u64; 4]) -> Self {
+        let value = F::from_bigint(BigInt(limbs))
+            .unwrap_or_else(|| F::from_le_bytes_mod_order(&BigInt(limbs).to_bytes_le()));
+        Self { value }
+    }
+
+    /// Returns the canonical little-endian 64-bit limbs of this element.
+    pub fn to_u64_limbs(&self) -> [u64; 4] {
+        self.value.into_bigint().0
+    }
+}
+
+impl<F: PrimeField> Serialize for FieldWrapper<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(self.value.into_bigint().to_bytes_be()))
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for FieldWrapper<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = hex::decode(&encoded).map_err(de::Error::custom)?;
+
+        // Only accept the canonical encoding: full width and below the modulus
+        let value = F::from_be_bytes_mod_order(&bytes);
+        if value.into_bigint().to_bytes_be() != bytes {
+            return Err(de::Error::custom(format!(
+                "non-canonical field element encoding: {}",
+                encoded
+            )));
+        }
+
+        Ok(Self { value })
+    }
 }
 
 impl<F: PrimeField> std::ops::Add for FieldWrapper<F> {
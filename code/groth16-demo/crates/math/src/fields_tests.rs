@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use crate::fields::FieldWrapper;
-    use ark_bn254::Fq;
+    use crate::error::FieldError;
+    use crate::fields::{FieldWrapper, BN254_FQ_MODULUS, BN254_FR_MODULUS};
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{PrimeField, UniformRand};
+    use num_bigint::BigUint;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
 
     #[test]
     fn test_field_wrapper_creation() {
@@ -16,4 +21,124 @@ mod tests {
         let sum = a + b;
         assert_eq!(sum.value, Fq::from(8u64));
     }
+
+    #[test]
+    fn test_field_wrapper_hex_serde_round_trip() {
+        let witness = vec![
+            FieldWrapper::<Fr>::from(1u64),
+            FieldWrapper::<Fr>::from(12u64),
+            FieldWrapper::<Fr>::from(3u64),
+            FieldWrapper::<Fr>::zero() - FieldWrapper::<Fr>::one(),
+        ];
+
+        let json = serde_json::to_string(&witness).unwrap();
+        let decoded: Vec<FieldWrapper<Fr>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(witness.len(), decoded.len());
+        for (original, restored) in witness.iter().zip(&decoded) {
+            assert_eq!(original.value, restored.value);
+        }
+
+        // 32 big-endian bytes, hex-encoded
+        let twelve = serde_json::to_string(&witness[1]).unwrap();
+        assert_eq!(twelve, format!("\"{}0c\"", "0".repeat(62)));
+    }
+
+    #[test]
+    fn test_field_wrapper_rejects_non_canonical_hex() {
+        // All ones exceeds the field modulus
+        let too_large = format!("\"{}\"", "f".repeat(64));
+        assert!(serde_json::from_str::<FieldWrapper<Fr>>(&too_large).is_err());
+
+        // Short encodings are not canonical either
+        assert!(serde_json::from_str::<FieldWrapper<Fr>>("\"0c\"").is_err());
+        assert!(serde_json::from_str::<FieldWrapper<Fr>>("\"zz\"").is_err());
+    }
+
+    #[test]
+    fn test_field_wrapper_modulus() {
+        let fr_order: BigUint = BN254_FR_MODULUS.parse().unwrap();
+        let fq_order: BigUint = BN254_FQ_MODULUS.parse().unwrap();
+
+        assert_eq!(FieldWrapper::<Fr>::modulus(), fr_order);
+        assert_eq!(FieldWrapper::<Fq>::modulus(), fq_order);
+
+        // p - 1 is the largest element and wraps to zero when incremented
+        let max = FieldWrapper::<Fr>::zero() - FieldWrapper::<Fr>::one();
+        let max_int: BigUint = max.value.into();
+        assert_eq!(max_int + 1u32, fr_order);
+    }
+
+    #[test]
+    fn test_u64_limbs_round_trip() {
+        let mut rng = ChaCha8Rng::from_seed([9u8; 32]);
+
+        for _ in 0..32 {
+            let x = FieldWrapper::<Fr> {
+                value: Fr::rand(&mut rng),
+            };
+            let limbs = x.to_u64_limbs();
+            assert_eq!(FieldWrapper::<Fr>::from_u64_limbs(limbs).value, x.value);
+        }
+
+        // Limbs are the little-endian integer, not Montgomery form
+        assert_eq!(
+            FieldWrapper::<Fr>::from(7u64).to_u64_limbs(),
+            [7u64, 0, 0, 0]
+        );
+        // Values above the modulus are reduced
+        assert_eq!(
+            FieldWrapper::<Fr>::from_u64_limbs([u64::MAX; 4]).value,
+            Fr::from_le_bytes_mod_order(&[0xff; 32])
+        );
+    }
+
+    #[test]
+    fn test_from_decimal_str_checked() {
+        let x = FieldWrapper::<Fr>::from_decimal_str("12").unwrap();
+        assert_eq!(x.value, Fr::from(12u64));
+
+        // The modulus itself and anything above it are rejected
+        assert!(matches!(
+            FieldWrapper::<Fr>::from_decimal_str(BN254_FR_MODULUS),
+            Err(FieldError::OutOfRange(_))
+        ));
+        let above = format!("{}0", BN254_FR_MODULUS);
+        assert!(FieldWrapper::<Fr>::from_decimal_str(&above).is_err());
+
+        assert!(matches!(
+            FieldWrapper::<Fr>::from_decimal_str("-1"),
+            Err(FieldError::InvalidDecimal(_))
+        ));
+        assert!(FieldWrapper::<Fr>::from_decimal_str("").is_err());
+    }
+
+    #[test]
+    fn test_from_be_bytes_checked() {
+        let x = FieldWrapper::<Fr>::from_be_bytes_checked(&[0x01, 0x00]).unwrap();
+        assert_eq!(x.value, Fr::from(256u64));
+
+        let modulus: BigUint = BN254_FR_MODULUS.parse().unwrap();
+        let at_modulus = modulus.to_bytes_be();
+        assert!(matches!(
+            FieldWrapper::<Fr>::from_be_bytes_checked(&at_modulus),
+            Err(FieldError::OutOfRange(_))
+        ));
+
+        let below = (modulus - 1u32).to_bytes_be();
+        let max = FieldWrapper::<Fr>::from_be_bytes_checked(&below).unwrap();
+        assert_eq!(max.value, -Fr::from(1u64));
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = ChaCha8Rng::from_seed([13u8; 32]);
+        for _ in 0..16 {
+            let a = FieldWrapper::<Fr>::from(Fr::rand(&mut rng));
+            let b = FieldWrapper::<Fr>::from(Fr::rand(&mut rng));
+
+            assert_eq!(FieldWrapper::select(true, &a, &b).value, a.value);
+            assert_eq!(FieldWrapper::select(false, &a, &b).value, b.value);
+        }
+    }
 }
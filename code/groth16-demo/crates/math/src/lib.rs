@@ -2,16 +2,25 @@
 //!
 //! This crate provides core mathematical primitives for Groth16:
 //! - Finite field operations
-//! - Bilinear pairings
+//! - Bilinear pairings over BN254 or BLS12-381 (see [`pairing::PairingCurve`])
 //! - Polynomial operations
+//! - Pedersen commitments
+//! - Fiat-Shamir transcripts
 
+pub mod commitment;
+pub mod error;
 pub mod fields;
 pub mod pairing;
 pub mod polynomial;
+pub mod transcript;
 
+#[cfg(test)]
+mod commitment_tests;
 #[cfg(test)]
 mod fields_tests;
 #[cfg(test)]
 mod pairing_tests;
 #[cfg(test)]
 mod polynomial_tests;
+#[cfg(test)]
+mod transcript_tests;
@@ -1,5 +1,59 @@
+use ark_bls12_381::Bls12_381;
 use ark_bn254::Bn254;
 use ark_ec::pairing::Pairing;
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::{CurveGroup, Group, VariableBaseMSM};
+use ark_ff::{Fp, FpConfig, PrimeField, Zero};
+
+/// Window width (in bits) used by [`ct_scalar_mul`]
+const CT_WINDOW_BITS: usize = 4;
+
+/// A pairing-friendly curve the Groth16 crates can be instantiated on.
+///
+/// Extends arkworks' [`Pairing`] with the curve-specific operations the
+/// prover needs. Implemented for BN254 (the default everywhere) and
+/// BLS12-381.
+pub trait PairingCurve: Pairing {
+    /// Human-readable curve name
+    const NAME: &'static str;
+
+    /// Multiplies a G₁ point by a secret scalar, see [`ct_scalar_mul`]
+    fn ct_g1_mul(point: Self::G1Affine, scalar: Self::ScalarField) -> Self::G1Affine;
+}
+
+impl PairingCurve for Bn254 {
+    const NAME: &'static str = "BN254";
+
+    fn ct_g1_mul(point: Self::G1Affine, scalar: Self::ScalarField) -> Self::G1Affine {
+        ct_scalar_mul(point, scalar)
+    }
+}
+
+impl PairingCurve for Bls12_381 {
+    const NAME: &'static str = "BLS12-381";
+
+    fn ct_g1_mul(point: Self::G1Affine, scalar: Self::ScalarField) -> Self::G1Affine {
+        ct_scalar_mul(point, scalar)
+    }
+}
+
+/// Links a scalar field back to the curve it belongs to.
+///
+/// Functions whose arguments are only field-typed, such as the QAP
+/// polynomials passed to the trusted setup, use this to infer the curve
+/// without a turbofish.
+pub trait CurveScalar: PrimeField {
+    /// The curve whose scalar field is `Self`
+    type Curve: PairingCurve<ScalarField = Self>;
+}
+
+impl CurveScalar for ark_bn254::Fr {
+    type Curve = Bn254;
+}
+
+impl CurveScalar for ark_bls12_381::Fr {
+    type Curve = Bls12_381;
+}
 
 pub struct PairingGroup;
 
@@ -16,3 +70,130 @@ impl PairingGroup {
         left == right
     }
 }
+
+/// Converts group points from projective to affine form in one batch.
+///
+/// Each `into_affine` call performs a field inversion. Batch normalization
+/// shares a single inversion across all points (Montgomery's trick), so
+/// converting n points costs one inversion plus about 3n multiplications.
+///
+/// # Example
+/// ```
+/// use ark_bn254::{Fr, G1Affine, G1Projective};
+/// use ark_ec::{AffineRepr, CurveGroup};
+/// use groth16_math::pairing::batch_to_affine;
+///
+/// let g = G1Affine::generator();
+/// let points: Vec<G1Projective> = (1..4u64).map(|k| g * Fr::from(k)).collect();
+/// assert_eq!(batch_to_affine(&points)[2], points[2].into_affine());
+/// ```
+pub fn batch_to_affine<G: CurveGroup>(points: &[G]) -> Vec<G::Affine> {
+    G::normalize_batch(points)
+}
+
+/// Computes the multi-scalar multiplication Σ scalars[i]·bases[i].
+///
+/// Uses arkworks' Pippenger implementation, which is far faster than
+/// summing one scalar multiplication per base, and runs on all cores when
+/// the `parallel` feature is enabled.
+///
+/// Unlike [`ct_scalar_mul`], the running time depends on the scalars.
+///
+/// # Panics
+/// If `bases` and `scalars` have different lengths, since dropping the
+/// extra terms would silently give a wrong sum.
+///
+/// # Example
+/// ```
+/// use groth16_math::pairing::msm;
+/// use ark_bn254::{Fr, G1Affine, G1Projective};
+/// use ark_ec::AffineRepr;
+///
+/// let g = G1Affine::generator();
+/// let sum: G1Projective = msm(&[g, g], &[Fr::from(2u64), Fr::from(3u64)]);
+/// assert_eq!(sum, g * Fr::from(5u64));
+/// ```
+pub fn msm<G: VariableBaseMSM>(bases: &[G::MulBase], scalars: &[G::ScalarField]) -> G {
+    assert_eq!(bases.len(), scalars.len(), "msm needs one scalar per base");
+    G::msm_unchecked(bases, scalars)
+}
+
+/// Multiplies a G₁ point by a secret scalar with a scalar-independent
+/// sequence of group operations.
+///
+/// Works on any short Weierstrass curve over a prime field, which covers
+/// G₁ of both BN254 and BLS12-381.
+///
+/// The default arkworks `point * scalar` skips work for zero bits, so its
+/// running time depends on the scalar. This uses a fixed 4-bit window ladder
+/// instead: every window costs four doublings and one addition, and the
+/// table entry is selected by scanning the whole table with masked limb
+/// copies rather than by indexing.
+///
+/// Prefer this whenever the scalar is a witness value. Note that the
+/// underlying arkworks group law still special-cases the point at infinity,
+/// so this hardens timing behaviour rather than guaranteeing it formally.
+///
+/// # Example
+/// ```
+/// use ark_bn254::{Fr, G1Affine};
+/// use ark_ec::{AffineRepr, CurveGroup};
+/// use groth16_math::pairing::ct_scalar_mul;
+///
+/// let g = G1Affine::generator();
+/// let s = Fr::from(42u64);
+/// assert_eq!(ct_scalar_mul(g, s), (g * s).into_affine());
+/// ```
+pub fn ct_scalar_mul<C, P, const N: usize>(point: Affine<C>, scalar: C::ScalarField) -> Affine<C>
+where
+    C: SWCurveConfig<BaseField = Fp<P, N>>,
+    P: FpConfig<N>,
+{
+    // table[i] = i·P for every window value i
+    let mut table = [Projective::<C>::zero(); 1 << CT_WINDOW_BITS];
+    for i in 1..table.len() {
+        table[i] = table[i - 1] + point;
+    }
+
+    let scalar = scalar.into_bigint();
+    let limbs = scalar.as_ref();
+    let num_windows = (limbs.len() * 64) / CT_WINDOW_BITS;
+
+    let mut acc = Projective::<C>::zero();
+    for window in (0..num_windows).rev() {
+        for _ in 0..CT_WINDOW_BITS {
+            acc.double_in_place();
+        }
+
+        let bit = window * CT_WINDOW_BITS;
+        let digit = (limbs[bit / 64] >> (bit % 64)) & ((1 << CT_WINDOW_BITS) - 1);
+        acc += ct_lookup(&table, digit);
+    }
+
+    acc.into_affine()
+}
+
+/// Reads `table[index]` touching every entry, without branching on `index`
+fn ct_lookup<C, P, const N: usize>(table: &[Projective<C>], index: u64) -> Projective<C>
+where
+    C: SWCurveConfig<BaseField = Fp<P, N>>,
+    P: FpConfig<N>,
+{
+    let mut selected = Projective::<C>::zero();
+    for (i, entry) in table.iter().enumerate() {
+        // All ones when i == index, all zeros otherwise
+        let diff = (i as u64) ^ index;
+        let mask = ((diff | diff.wrapping_neg()) >> 63).wrapping_sub(1);
+        ct_assign(&mut selected.x, &entry.x, mask);
+        ct_assign(&mut selected.y, &entry.y, mask);
+        ct_assign(&mut selected.z, &entry.z, mask);
+    }
+    selected
+}
+
+/// Overwrites `dst` with `src` where `mask` is all ones, keeps it otherwise
+fn ct_assign<P: FpConfig<N>, const N: usize>(dst: &mut Fp<P, N>, src: &Fp<P, N>, mask: u64) {
+    for (d, s) in dst.0 .0.iter_mut().zip(src.0 .0.iter()) {
+        *d = (*d & !mask) | (*s & mask);
+    }
+}
@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use crate::pairing::PairingGroup;
-    use ark_bn254::{Bn254, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2};
+    use crate::pairing::{batch_to_affine, ct_scalar_mul, msm, PairingGroup};
+    use ark_bn254::{Bn254, Fr, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2};
     use ark_ec::pairing::Pairing;
-    use ark_ec::AffineRepr;
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{Field, Zero};
 
     #[test]
     fn test_pairing_bilinearity() {
@@ -37,4 +38,72 @@ mod tests {
             &g2
         ));
     }
+
+    #[test]
+    fn test_ct_scalar_mul_matches_variable_time() {
+        let g = G1Affine::generator();
+        let p = (g * Fr::from(7u64)).into_affine();
+
+        let scalars = [
+            Fr::zero(),
+            Fr::from(1u64),
+            Fr::from(15u64),
+            Fr::from(16u64),
+            Fr::from(u64::MAX),
+            -Fr::from(1u64),
+            Fr::from(123456789u64).pow([5u64]),
+        ];
+
+        for scalar in scalars {
+            assert_eq!(ct_scalar_mul(p, scalar), (p * scalar).into_affine());
+        }
+    }
+
+    #[test]
+    fn test_msm_matches_naive_sum() {
+        use ark_ff::UniformRand;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let mut rng = ChaCha8Rng::from_seed([9u8; 32]);
+        let bases: Vec<G1Affine> = (0..64).map(|_| G1::rand(&mut rng).into_affine()).collect();
+        let mut scalars: Vec<Fr> = (0..64).map(|_| Fr::rand(&mut rng)).collect();
+        scalars[3] = Fr::zero();
+
+        let naive = bases
+            .iter()
+            .zip(&scalars)
+            .fold(G1::zero(), |acc, (base, scalar)| acc + *base * scalar);
+        assert_eq!(msm::<G1>(&bases, &scalars), naive);
+
+        assert!(msm::<G1>(&[], &[]).is_zero());
+
+        // Works on G₂ as well
+        let g2 = G2Affine::generator();
+        let sum: G2 = msm(&[g2, g2], &[Fr::from(2u64), Fr::from(5u64)]);
+        assert_eq!(sum, g2 * Fr::from(7u64));
+    }
+
+    #[test]
+    #[should_panic(expected = "msm needs one scalar per base")]
+    fn test_msm_rejects_mismatched_lengths() {
+        let g = G1Affine::generator();
+        let _: G1 = msm(&[g, g, g], &[Fr::from(2u64), Fr::from(3u64)]);
+    }
+
+    #[test]
+    fn test_batch_to_affine_matches_into_affine() {
+        let g = G1Affine::generator();
+        let mut points: Vec<G1> = (1..=10u64).map(|k| g * Fr::from(k * k + 3)).collect();
+        // The identity has no affine inverse to share; it must still convert
+        points.push(G1::zero());
+
+        let batch = batch_to_affine(&points);
+        assert_eq!(batch.len(), points.len());
+        for (affine, point) in batch.iter().zip(&points) {
+            assert_eq!(*affine, point.into_affine());
+        }
+
+        assert!(batch_to_affine::<G1>(&[]).is_empty());
+    }
 }
@@ -34,6 +34,169 @@ impl<F: PrimeField> Polynomial<F> {
     pub fn is_zero(&self) -> bool {
         self.coeffs.iter().all(|c| c.value.is_zero())
     }
+
+    /// Computes the monic greatest common divisor of two polynomials.
+    ///
+    /// Uses the Euclidean algorithm over the field: repeatedly replace
+    /// (a, b) with (b, a mod b) until b is zero, then scale a so its leading
+    /// coefficient is 1. The GCD of two zero polynomials is the zero
+    /// polynomial.
+    ///
+    /// Useful when debugging QAP satisfaction: if t(x) divides p(x), then
+    /// gcd(p, t) is the monic form of t.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = trimmed(&self.coeffs);
+        let mut b = trimmed(&other.coeffs);
+
+        while !b.is_empty() {
+            let r = remainder(&a, &b);
+            a = b;
+            b = r;
+        }
+
+        if let Some(lead) = a.last().copied() {
+            let lead_inv = lead.inverse().expect("leading coefficient is non-zero");
+            for coeff in a.iter_mut() {
+                *coeff *= lead_inv;
+            }
+        }
+
+        Self::new(a.into_iter().map(|value| FieldWrapper { value }).collect())
+    }
+}
+
+/// Formats the polynomial in human-readable form, highest degree first.
+///
+/// Zero terms are skipped and unit coefficients omitted, e.g.
+/// `2x^2 + 3x + 1` or `x^3 + 5`. The zero polynomial prints as `0`.
+impl<F: PrimeField> std::fmt::Display for Polynomial<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+
+        for (power, coeff) in self.coeffs.iter().enumerate().rev() {
+            if coeff.value.is_zero() {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+
+            if power == 0 || !coeff.value.is_one() {
+                write!(f, "{}", coeff.value)?;
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Polynomial<F> {
+    /// Parses a polynomial written like `2x^2 + 3x + 1`.
+    ///
+    /// Accepts the output of `Display` plus a few conveniences for typing by
+    /// hand: terms may appear in any order, repeated powers are summed, and
+    /// `-` is allowed between terms (the coefficient is negated in the
+    /// field). Coefficients must be non-negative decimal integers.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_bn254::Fr;
+    /// use groth16_math::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::<Fr>::parse("2x^2 + 3x + 1").unwrap();
+    /// assert_eq!(p.to_string(), "2x^2 + 3x + 1");
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let normalized = s.replace(' ', "").replace('-', "+-");
+        let mut coeffs: Vec<FieldWrapper<F>> = Vec::new();
+
+        for term in normalized.split('+') {
+            if term.is_empty() {
+                continue;
+            }
+            let (negative, term) = match term.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, term),
+            };
+
+            let (coeff_str, power) = match term.split_once('x') {
+                None => (term, 0),
+                Some((coeff, "")) => (coeff, 1),
+                Some((coeff, exp)) => {
+                    let exp = exp
+                        .strip_prefix('^')
+                        .ok_or_else(|| format!("Invalid term: {}", term))?;
+                    let power = exp
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid exponent: {}", exp))?;
+                    (coeff, power)
+                }
+            };
+
+            let mut coeff = if coeff_str.is_empty() {
+                F::one()
+            } else if coeff_str.bytes().all(|b| b.is_ascii_digit()) {
+                F::from_str(coeff_str).map_err(|_| format!("Invalid coefficient: {}", coeff_str))?
+            } else {
+                return Err(format!("Invalid coefficient: {}", coeff_str));
+            };
+            if negative {
+                coeff = -coeff;
+            }
+
+            if coeffs.len() <= power {
+                coeffs.resize(power + 1, FieldWrapper::zero());
+            }
+            coeffs[power].value += coeff;
+        }
+
+        while coeffs.last().is_some_and(|c| c.value.is_zero()) {
+            coeffs.pop();
+        }
+
+        Ok(Self::new(coeffs))
+    }
+}
+
+/// Returns raw coefficients with trailing zeros removed (empty for zero)
+fn trimmed<F: PrimeField>(coeffs: &[FieldWrapper<F>]) -> Vec<F> {
+    let mut values: Vec<F> = coeffs.iter().map(|c| c.value).collect();
+    while values.last().is_some_and(|c| c.is_zero()) {
+        values.pop();
+    }
+    values
+}
+
+/// Computes a mod b for trimmed coefficient vectors, b non-empty
+fn remainder<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut rem = a.to_vec();
+    let lead_inv = b
+        .last()
+        .and_then(|c| c.inverse())
+        .expect("divisor is non-zero");
+
+    while rem.len() >= b.len() {
+        let factor = *rem.last().unwrap() * lead_inv;
+        let shift = rem.len() - b.len();
+        for (i, coeff) in b.iter().enumerate() {
+            rem[shift + i] -= factor * coeff;
+        }
+        // Leading term is now zero; drop it along with any further zeros
+        while rem.last().is_some_and(|c| c.is_zero()) {
+            rem.pop();
+        }
+    }
+
+    rem
 }
 
 impl<F: PrimeField> std::ops::Mul for Polynomial<F> {
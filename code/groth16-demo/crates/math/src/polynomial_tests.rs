@@ -18,4 +18,83 @@ mod tests {
         let result = poly.evaluate(&x);
         assert_eq!(result.value, Fq::from(13u64));
     }
+
+    #[test]
+    fn test_gcd_divisor_is_monic() {
+        // gcd(x² - 1, 2x - 2) = x - 1
+        let minus_one = FieldWrapper::<Fq>::zero() - FieldWrapper::<Fq>::one();
+        let p = Polynomial::new(vec![
+            minus_one.clone(),
+            FieldWrapper::<Fq>::zero(),
+            FieldWrapper::<Fq>::one(),
+        ]);
+        let q = Polynomial::new(vec![
+            FieldWrapper::<Fq>::zero() - FieldWrapper::<Fq>::from(2u64),
+            FieldWrapper::<Fq>::from(2u64),
+        ]);
+
+        let gcd = p.gcd(&q);
+        let values: Vec<Fq> = gcd.coeffs.iter().map(|c| c.value).collect();
+        assert_eq!(values, vec![minus_one.value, Fq::from(1u64)]);
+    }
+
+    #[test]
+    fn test_gcd_with_self() {
+        // gcd(p, p) is the monic form of p: 3x² + 6x + 9 -> x² + 2x + 3
+        let p = Polynomial::new(vec![
+            FieldWrapper::<Fq>::from(9u64),
+            FieldWrapper::<Fq>::from(6u64),
+            FieldWrapper::<Fq>::from(3u64),
+        ]);
+
+        let gcd = p.gcd(&p);
+        let values: Vec<Fq> = gcd.coeffs.iter().map(|c| c.value).collect();
+        assert_eq!(values, vec![Fq::from(3u64), Fq::from(2u64), Fq::from(1u64)]);
+    }
+
+    #[test]
+    fn test_polynomial_display_skips_zero_terms() {
+        // 2x² + 0x + 1
+        let p = Polynomial::new(vec![
+            FieldWrapper::<Fq>::from(1u64),
+            FieldWrapper::<Fq>::zero(),
+            FieldWrapper::<Fq>::from(2u64),
+        ]);
+        assert_eq!(p.to_string(), "2x^2 + 1");
+
+        // x³ + x
+        let q = Polynomial::new(vec![
+            FieldWrapper::<Fq>::zero(),
+            FieldWrapper::<Fq>::one(),
+            FieldWrapper::<Fq>::zero(),
+            FieldWrapper::<Fq>::one(),
+        ]);
+        assert_eq!(q.to_string(), "x^3 + x");
+
+        assert_eq!(Polynomial::<Fq>::new(vec![]).to_string(), "0");
+        assert_eq!(
+            Polynomial::new(vec![FieldWrapper::<Fq>::zero()]).to_string(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn test_polynomial_parse_round_trip() {
+        for input in ["2x^2 + 3x + 1", "x^3 + 5", "7x", "42", "0"] {
+            let p = Polynomial::<Fq>::parse(input).unwrap();
+            assert_eq!(p.to_string(), input);
+        }
+
+        // Subtraction maps to field negation and still round-trips
+        let p = Polynomial::<Fq>::parse("x^2 - 1").unwrap();
+        let reparsed = Polynomial::<Fq>::parse(&p.to_string()).unwrap();
+        assert_eq!(p.coeffs.len(), reparsed.coeffs.len());
+        for (a, b) in p.coeffs.iter().zip(&reparsed.coeffs) {
+            assert_eq!(a.value, b.value);
+        }
+        assert_eq!(p.evaluate(&FieldWrapper::<Fq>::one()).value, Fq::from(0u64));
+
+        assert!(Polynomial::<Fq>::parse("2y + 1").is_err());
+        assert!(Polynomial::<Fq>::parse("x^").is_err());
+    }
 }
@@ -0,0 +1,75 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use sha2::{Digest, Sha256};
+
+/// Domain separator mixed into every transcript before any user data
+const TRANSCRIPT_DOMAIN: &[u8] = b"groth16-demo/transcript/v1";
+
+/// Fiat-Shamir transcript for building non-interactive protocols.
+///
+/// A minimal, Merlin-style transcript backed by a SHA-256 sponge. The prover
+/// and verifier append the same labeled messages in the same order and then
+/// derive identical challenges, replacing the verifier's random coins.
+///
+/// # Domain Separation
+/// Every message is absorbed as `len(label) || label || len(msg) || msg`,
+/// so distinct (label, message) sequences can never produce the same hash
+/// input. Each challenge is fed back into the state, so successive challenges
+/// with the same label are independent.
+///
+/// # Example
+/// ```
+/// use groth16_math::transcript::Transcript;
+///
+/// let mut prover = Transcript::new(b"example");
+/// prover.append_message(b"commitment", b"some bytes");
+/// let c1 = prover.challenge_scalar(b"challenge");
+///
+/// let mut verifier = Transcript::new(b"example");
+/// verifier.append_message(b"commitment", b"some bytes");
+/// let c2 = verifier.challenge_scalar(b"challenge");
+///
+/// assert_eq!(c1, c2);
+/// ```
+#[derive(Clone)]
+pub struct Transcript {
+    state: Sha256,
+}
+
+impl Transcript {
+    /// Creates a new transcript bound to a protocol label.
+    pub fn new(protocol_label: &[u8]) -> Self {
+        let mut transcript = Self {
+            state: Sha256::new(),
+        };
+        transcript.append_message(b"dom-sep", TRANSCRIPT_DOMAIN);
+        transcript.append_message(b"protocol", protocol_label);
+        transcript
+    }
+
+    /// Absorbs a labeled message into the transcript.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.state.update((label.len() as u64).to_le_bytes());
+        self.state.update(label);
+        self.state.update((message.len() as u64).to_le_bytes());
+        self.state.update(message);
+    }
+
+    /// Derives a challenge scalar from everything absorbed so far.
+    ///
+    /// 64 bytes of hash output are reduced modulo the field order to keep the
+    /// bias negligible. The challenge is appended back into the transcript.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        self.append_message(b"challenge", label);
+
+        let mut wide = [0u8; 64];
+        for (i, chunk) in wide.chunks_mut(32).enumerate() {
+            let mut hasher = self.state.clone();
+            hasher.update([i as u8]);
+            chunk.copy_from_slice(&hasher.finalize());
+        }
+
+        self.append_message(b"challenge-output", &wide);
+        Fr::from_le_bytes_mod_order(&wide)
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::transcript::Transcript;
+
+    #[test]
+    fn test_transcript_deterministic() {
+        let mut t1 = Transcript::new(b"test");
+        t1.append_message(b"a", b"first");
+        t1.append_message(b"b", b"second");
+
+        let mut t2 = Transcript::new(b"test");
+        t2.append_message(b"a", b"first");
+        t2.append_message(b"b", b"second");
+
+        assert_eq!(t1.challenge_scalar(b"c"), t2.challenge_scalar(b"c"));
+    }
+
+    #[test]
+    fn test_transcript_order_matters() {
+        let mut t1 = Transcript::new(b"test");
+        t1.append_message(b"a", b"first");
+        t1.append_message(b"b", b"second");
+
+        let mut t2 = Transcript::new(b"test");
+        t2.append_message(b"b", b"second");
+        t2.append_message(b"a", b"first");
+
+        assert_ne!(t1.challenge_scalar(b"c"), t2.challenge_scalar(b"c"));
+    }
+
+    #[test]
+    fn test_transcript_successive_challenges_differ() {
+        let mut t = Transcript::new(b"test");
+        t.append_message(b"a", b"first");
+
+        let c1 = t.challenge_scalar(b"c");
+        let c2 = t.challenge_scalar(b"c");
+        assert_ne!(c1, c2);
+    }
+}
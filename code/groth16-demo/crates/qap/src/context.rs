@@ -0,0 +1,219 @@
+use crate::error::QapError;
+use crate::polynomials::QapPolynomials;
+use ark_ff::PrimeField;
+use groth16_math::fields::FieldWrapper;
+use groth16_math::polynomial::Polynomial;
+use groth16_r1cs::constraint::R1CSConstraint;
+use std::collections::HashMap;
+
+/// Precomputed Lagrange basis for building QAPs with a fixed constraint count.
+///
+/// [`r1cs_to_qap`](crate::polynomials::r1cs_to_qap) interpolates every
+/// variable polynomial from scratch, recomputing the Lagrange basis
+/// Lᵢ(x) = Πⱼ≠ᵢ (x - j) / (i - j) over the points {1..n} each time.
+/// The basis only depends on n, so a `QapContext` computes it once and
+/// builds each polynomial as the linear combination Σᵢ coeffᵢ · Lᵢ(x).
+///
+/// Useful when iterating on a circuit and rebuilding the QAP repeatedly
+/// while the number of constraints stays the same. A context for a single
+/// constraint pads it with a duplicate, exactly like `r1cs_to_qap`.
+///
+/// # Example
+/// ```rust
+/// use groth16_qap::context::QapContext;
+/// use groth16_r1cs::constraint::R1CSConstraint;
+/// use groth16_math::fields::FieldWrapper;
+/// use ark_bn254::Fr;
+///
+/// let mut c1 = R1CSConstraint::<Fr>::new();
+/// c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+/// c1.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+/// c1.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+///
+/// let context = QapContext::<Fr>::new(2).unwrap();
+/// let (a, b, c) = context.r1cs_to_qap_cached(&[c1.clone(), c1], 4).unwrap();
+/// assert_eq!(a.len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct QapContext<F: PrimeField> {
+    /// Number of constraints the context accepts
+    num_constraints: usize,
+    /// Lagrange basis polynomials L₁..Lₙ, as raw coefficient vectors
+    basis: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> QapContext<F> {
+    /// Precomputes the Lagrange basis for `num_constraints` points {1..n}.
+    ///
+    /// # Returns
+    /// * `Ok(context)` - The cached basis
+    /// * `Err(QapError::EmptyConstraints)` - If `num_constraints` is 0
+    pub fn new(num_constraints: usize) -> Result<Self, QapError> {
+        if num_constraints == 0 {
+            return Err(QapError::EmptyConstraints);
+        }
+
+        // A single constraint is interpolated over two points, see r1cs_to_qap
+        let n = num_constraints.max(2);
+        let mut basis = Vec::with_capacity(n);
+
+        for i in 1..=n {
+            let xi = F::from(i as u64);
+            let mut coeffs = vec![F::one()];
+            let mut denominator = F::one();
+
+            for j in (1..=n).filter(|&j| j != i) {
+                let xj = F::from(j as u64);
+
+                // Multiply by (x - xj)
+                let mut next = vec![F::zero(); coeffs.len() + 1];
+                for (k, coeff) in coeffs.iter().enumerate() {
+                    next[k + 1] += coeff;
+                    next[k] -= xj * coeff;
+                }
+                coeffs = next;
+                denominator *= xi - xj;
+            }
+
+            let inv = denominator
+                .inverse()
+                .expect("Interpolation points 1..n are distinct");
+            for coeff in coeffs.iter_mut() {
+                *coeff *= inv;
+            }
+            basis.push(coeffs);
+        }
+
+        Ok(Self {
+            num_constraints,
+            basis,
+        })
+    }
+
+    /// Returns the number of constraints this context was built for.
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// Transforms R1CS constraints into a QAP using the cached basis.
+    ///
+    /// Produces exactly the same polynomials as `r1cs_to_qap`.
+    ///
+    /// # Returns
+    /// * `Ok((A, B, C))` - Three vectors of polynomials, each of length num_variables
+    /// * `Err(QapError::ConstraintCountMismatch)` - If the number of constraints
+    ///   differs from the one the context was built for
+    pub fn r1cs_to_qap_cached(
+        &self,
+        constraints: &[R1CSConstraint<F>],
+        num_variables: usize,
+    ) -> Result<QapPolynomials<F>, QapError> {
+        if constraints.len() != self.num_constraints() {
+            return Err(QapError::ConstraintCountMismatch {
+                expected: self.num_constraints(),
+                actual: constraints.len(),
+            });
+        }
+
+        let mut a_polys = Vec::with_capacity(num_variables);
+        let mut b_polys = Vec::with_capacity(num_variables);
+        let mut c_polys = Vec::with_capacity(num_variables);
+
+        // Pad a single constraint with a duplicate to fill the basis
+        let rows = || constraints.iter().cycle().take(self.basis.len());
+
+        for j in 0..num_variables {
+            a_polys.push(self.combine(rows().map(|c| &c.a), j));
+            b_polys.push(self.combine(rows().map(|c| &c.b), j));
+            c_polys.push(self.combine(rows().map(|c| &c.c), j));
+        }
+
+        Ok((a_polys, b_polys, c_polys))
+    }
+
+    /// Computes Σᵢ coeff(i, j) · Lᵢ(x) for variable j
+    fn combine<'a>(
+        &self,
+        rows: impl Iterator<Item = &'a HashMap<usize, FieldWrapper<F>>>,
+        j: usize,
+    ) -> Polynomial<F> {
+        let mut result = vec![F::zero(); self.basis.len()];
+
+        for (row, basis) in rows.zip(&self.basis) {
+            if let Some(coeff) = row.get(&j) {
+                for (acc, b) in result.iter_mut().zip(basis) {
+                    *acc += coeff.value * b;
+                }
+            }
+        }
+
+        // Match lagrange_interpolate: trim trailing zeros but keep one term
+        while result.len() > 1 && result.last().unwrap().is_zero() {
+            result.pop();
+        }
+
+        Polynomial::new(result.into_iter().map(FieldWrapper::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::r1cs_to_qap;
+    use ark_bn254::Fr;
+
+    fn coeff_values(polys: &[Polynomial<Fr>]) -> Vec<Vec<Fr>> {
+        polys
+            .iter()
+            .map(|p| p.coeffs.iter().map(|c| c.value).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_cached_matches_r1cs_to_qap() {
+        // x * x = x², x² * x = x³, (x³ + 2x + 5) * 1 = y
+        // Witness layout: [1, y, x, x², x³]
+        let mut c1 = R1CSConstraint::<Fr>::new();
+        c1.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_b_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c1.add_c_variable(3, FieldWrapper::<Fr>::from(1u64));
+
+        let mut c2 = R1CSConstraint::<Fr>::new();
+        c2.add_a_variable(3, FieldWrapper::<Fr>::from(1u64));
+        c2.add_b_variable(2, FieldWrapper::<Fr>::from(1u64));
+        c2.add_c_variable(4, FieldWrapper::<Fr>::from(1u64));
+
+        let mut c3 = R1CSConstraint::<Fr>::new();
+        c3.add_a_variable(4, FieldWrapper::<Fr>::from(1u64));
+        c3.add_a_variable(2, FieldWrapper::<Fr>::from(2u64));
+        c3.add_a_variable(0, FieldWrapper::<Fr>::from(5u64));
+        c3.add_b_variable(0, FieldWrapper::<Fr>::from(1u64));
+        c3.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+
+        let constraints = vec![c1, c2, c3];
+        let expected = r1cs_to_qap(&constraints, 5).unwrap();
+
+        let context = QapContext::<Fr>::new(3).unwrap();
+        // Building twice reuses the same basis
+        for _ in 0..2 {
+            let cached = context.r1cs_to_qap_cached(&constraints, 5).unwrap();
+            assert_eq!(coeff_values(&cached.0), coeff_values(&expected.0));
+            assert_eq!(coeff_values(&cached.1), coeff_values(&expected.1));
+            assert_eq!(coeff_values(&cached.2), coeff_values(&expected.2));
+        }
+    }
+
+    #[test]
+    fn test_cached_rejects_wrong_constraint_count() {
+        let context = QapContext::<Fr>::new(3).unwrap();
+        let constraints = vec![R1CSConstraint::<Fr>::new(); 2];
+
+        assert!(matches!(
+            context.r1cs_to_qap_cached(&constraints, 4),
+            Err(QapError::ConstraintCountMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::error::QapError;
+use crate::polynomials::r1cs_to_qap;
 use ark_ff::PrimeField;
 use groth16_math::{fields::FieldWrapper, polynomial::Polynomial};
+use groth16_r1cs::constraint::R1CSConstraint;
 
 /// Checks if a witness polynomial is divisible by the target polynomial.
 ///
@@ -98,6 +100,36 @@ where
     Ok(remainder.is_zero())
 }
 
+/// Checks that a witness satisfies an R1CS both directly and through its QAP.
+///
+/// Builds the QAP with [`r1cs_to_qap`] and the matching target polynomial,
+/// then runs [`check_divisibility`]. A correct transformation agrees with the
+/// direct check, so circuit authors can use this to catch interpolation bugs
+/// or constraints that only look satisfied in one representation.
+///
+/// # Returns
+/// * `Ok(true)` - Every constraint is satisfied and p(x) is divisible by t(x)
+/// * `Ok(false)` - Either check fails
+/// * `Err(QapError)` - If the QAP cannot be built or the witness length is
+///   not `num_variables`
+pub fn verify_r1cs_qap_consistency<F>(
+    constraints: &[R1CSConstraint<F>],
+    num_variables: usize,
+    witness: &[FieldWrapper<F>],
+) -> Result<bool, QapError>
+where
+    F: PrimeField,
+{
+    let (a_polys, b_polys, c_polys) = r1cs_to_qap(constraints, num_variables)?;
+
+    // r1cs_to_qap pads a single constraint to two interpolation points
+    let target = target_polynomial::<F>(constraints.len().max(2));
+    let divides = check_divisibility(witness, &a_polys, &b_polys, &c_polys, &target)?;
+
+    let satisfied = constraints.iter().all(|c| c.is_satisfied(witness));
+    Ok(satisfied && divides)
+}
+
 /// Performs polynomial long division to divide one polynomial by another.
 ///
 /// Returns the quotient and remainder such that:
@@ -12,12 +12,15 @@ pub enum QapError {
     #[error("No points provided for interpolation")]
     EmptyPoints,
 
-    #[error("Duplicate x-value in interpolation points: {0}")]
-    DuplicateX(String),
+    #[error("Duplicate x-coordinate in interpolation points: {0}")]
+    DuplicateInterpolationPoint(String),
 
     #[error("Mismatched lengths: witness has {0} elements but polynomials have {1}")]
     MismatchedLengths(usize, usize),
 
+    #[error("Constraint count mismatch: expected {expected}, got {actual}")]
+    ConstraintCountMismatch { expected: usize, actual: usize },
+
     #[error("Division by zero polynomial")]
     DivisionByZero,
 }
@@ -2,12 +2,15 @@
 //!
 //! This crate provides QAP representation and R1CS to QAP transformation:
 //! - R1CS to QAP conversion using Lagrange interpolation
+//! - Cached Lagrange bases for repeated QAP builds
 //! - Polynomial divisibility checking
 
+pub mod context;
 pub mod divisibility;
 pub mod error;
 pub mod polynomials;
 
-pub use divisibility::{check_divisibility, target_polynomial};
+pub use context::QapContext;
+pub use divisibility::{check_divisibility, target_polynomial, verify_r1cs_qap_consistency};
 pub use error::QapError;
 pub use polynomials::{lagrange_interpolate, r1cs_to_qap};
@@ -22,7 +22,13 @@ pub type QapPolynomials<F> = (Vec<Polynomial<F>>, Vec<Polynomial<F>>, Vec<Polyno
 /// # Returns
 /// * `Ok((A, B, C))` - Three vectors of polynomials, each of length num_variables
 /// * `Err(QapError::EmptyConstraints)` - If constraints slice is empty
-/// * `Err(QapError::InsufficientConstraints)` - If fewer than 2 constraints
+///
+/// # Single Constraint
+/// Interpolation needs at least two points, so a lone constraint is padded
+/// with a copy of itself: the QAP is built as if the system were
+/// `[constraint, constraint]`. Any witness satisfying the original
+/// constraint satisfies both copies, so this does not change which
+/// witnesses are valid; the QAP simply has degree 1 over the points {1, 2}.
 ///
 /// # Algorithm
 /// 1. Extract coefficient values for each variable across all constraints
@@ -69,10 +75,15 @@ where
         return Err(QapError::EmptyConstraints);
     }
 
-    // Need at least 2 constraints for interpolation
-    if constraints.len() < 2 {
-        return Err(QapError::InsufficientConstraints);
-    }
+    // Need at least 2 points for interpolation: pad a single constraint
+    // with a duplicate of itself
+    let padded;
+    let constraints = if constraints.len() == 1 {
+        padded = [constraints[0].clone(), constraints[0].clone()];
+        &padded[..]
+    } else {
+        constraints
+    };
 
     let n = constraints.len();
 
@@ -135,12 +146,12 @@ where
 ///
 /// # Arguments
 /// * `points` - Slice of (x, y) points, where x is the constraint index (1-based)
-///              and y is the coefficient value for that variable
+///   and y is the coefficient value for that variable
 ///
 /// # Returns
 /// * `Ok(polynomial)` - The interpolated polynomial
 /// * `Err(QapError::EmptyPoints)` - If points slice is empty
-/// * `Err(QapError::DuplicateX)` - If x-values are not distinct
+/// * `Err(QapError::DuplicateInterpolationPoint)` - If two points share an x-coordinate
 ///
 /// # Algorithm
 /// Uses Lagrange basis polynomials:
@@ -164,10 +175,9 @@ where
     for i in 0..n {
         for j in (i + 1)..n {
             if points[i].0 == points[j].0 {
-                return Err(QapError::DuplicateX(format!(
-                    "x-value {:?} appears at indices {} and {}",
-                    points[i].0, i, j
-                )));
+                return Err(QapError::DuplicateInterpolationPoint(
+                    points[i].0.to_string(),
+                ));
             }
         }
     }
@@ -253,15 +263,31 @@ mod tests {
         constraint.add_b_variable(1, FieldWrapper::<Fq>::from(1u64));
         constraint.add_c_variable(2, FieldWrapper::<Fq>::from(1u64));
 
-        let constraints = vec![constraint];
+        let constraints = vec![constraint.clone()];
         let num_vars = 3;
 
-        let result = r1cs_to_qap(&constraints, num_vars);
-        assert!(result.is_err());
-        assert!(matches!(
-            result.unwrap_err(),
-            QapError::InsufficientConstraints
-        ));
+        // A single constraint is padded with a duplicate of itself
+        let (a_polys, b_polys, c_polys) = r1cs_to_qap(&constraints, num_vars).unwrap();
+        let (a_padded, b_padded, c_padded) =
+            r1cs_to_qap(&[constraint.clone(), constraint], num_vars).unwrap();
+
+        for (single, padded) in [
+            (&a_polys, &a_padded),
+            (&b_polys, &b_padded),
+            (&c_polys, &c_padded),
+        ] {
+            for (p, q) in single.iter().zip(padded.iter()) {
+                let p_values: Vec<Fq> = p.coeffs.iter().map(|c| c.value).collect();
+                let q_values: Vec<Fq> = q.coeffs.iter().map(|c| c.value).collect();
+                assert_eq!(p_values, q_values);
+            }
+        }
+
+        // A₀ is 1 at both interpolation points
+        let one = FieldWrapper::<Fq>::from(1u64);
+        let two = FieldWrapper::<Fq>::from(2u64);
+        assert_eq!(a_polys[0].evaluate(&one).value, Fq::from(1u64));
+        assert_eq!(a_polys[0].evaluate(&two).value, Fq::from(1u64));
     }
 
     #[test]
@@ -325,6 +351,22 @@ mod tests {
         assert!(matches!(result.unwrap_err(), QapError::EmptyConstraints));
     }
 
+    #[test]
+    fn test_duplicate_interpolation_point() {
+        // Two points share x = 2, which would make (xᵢ - xⱼ) zero
+        let points = vec![
+            (Fq::from(1u64), FieldWrapper::<Fq>::from(5u64)),
+            (Fq::from(2u64), FieldWrapper::<Fq>::from(7u64)),
+            (Fq::from(2u64), FieldWrapper::<Fq>::from(9u64)),
+        ];
+
+        let result = lagrange_interpolate(&points);
+        match result {
+            Err(QapError::DuplicateInterpolationPoint(x)) => assert_eq!(x, "2"),
+            _ => panic!("expected DuplicateInterpolationPoint error"),
+        }
+    }
+
     #[test]
     fn test_empty_points() {
         let points: Vec<(Fq, FieldWrapper<Fq>)> = vec![];
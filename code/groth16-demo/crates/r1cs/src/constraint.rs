@@ -59,6 +59,28 @@ impl<F: PrimeField> R1CSConstraint<F> {
         }
     }
 
+    /// Starts a fluent builder for a constraint.
+    ///
+    /// # Example
+    /// ```
+    /// use groth16_r1cs::constraint::R1CSConstraint;
+    /// use groth16_math::fields::FieldWrapper;
+    /// use ark_bn254::Fr;
+    ///
+    /// // var[2] * var[3] = var[1]
+    /// let constraint = R1CSConstraint::<Fr>::builder()
+    ///     .a(2, FieldWrapper::<Fr>::from(1u64))
+    ///     .b(3, FieldWrapper::<Fr>::from(1u64))
+    ///     .c(1, FieldWrapper::<Fr>::from(1u64))
+    ///     .build();
+    /// assert_eq!(constraint.unique_variable_count(), 3);
+    /// ```
+    pub fn builder() -> R1CSConstraintBuilder<F> {
+        R1CSConstraintBuilder {
+            constraint: Self::new(),
+        }
+    }
+
     /// Adds a variable with coefficient to the A vector.
     pub fn add_a_variable(&mut self, index: usize, coeff: FieldWrapper<F>) {
         self.a.insert(index, coeff);
@@ -124,13 +146,42 @@ impl<F: PrimeField> R1CSConstraint<F> {
     /// assert!(constraint.is_satisfied(&witness));
     /// ```
     pub fn is_satisfied(&self, witness: &[FieldWrapper<F>]) -> bool {
+        // Check: a · b = c
+        self.residual(witness).value.is_zero()
+    }
+
+    /// Computes how far a witness is from satisfying this constraint.
+    ///
+    /// Returns <a, witness> · <b, witness> - <c, witness>, which is zero
+    /// exactly when [`is_satisfied`](Self::is_satisfied) holds.
+    ///
+    /// # Example
+    /// ```
+    /// use groth16_r1cs::constraint::R1CSConstraint;
+    /// use groth16_math::fields::FieldWrapper;
+    /// use ark_bn254::Fq;
+    ///
+    /// // Constraint: a * b = c, with witness [3, 4, 10]
+    /// let mut constraint = R1CSConstraint::<Fq>::new();
+    /// constraint.add_a_variable(0, FieldWrapper::<Fq>::from(1u64));
+    /// constraint.add_b_variable(1, FieldWrapper::<Fq>::from(1u64));
+    /// constraint.add_c_variable(2, FieldWrapper::<Fq>::from(1u64));
+    ///
+    /// let witness = vec![
+    ///     FieldWrapper::<Fq>::from(3u64),
+    ///     FieldWrapper::<Fq>::from(4u64),
+    ///     FieldWrapper::<Fq>::from(10u64),
+    /// ];
+    ///
+    /// // 3 * 4 - 10 = 2
+    /// assert_eq!(constraint.residual(&witness).value, Fq::from(2u64));
+    /// ```
+    pub fn residual(&self, witness: &[FieldWrapper<F>]) -> FieldWrapper<F> {
         let a_value = self.evaluate_linear_combination(&self.a, witness);
         let b_value = self.evaluate_linear_combination(&self.b, witness);
         let c_value = self.evaluate_linear_combination(&self.c, witness);
 
-        // Check: a · b = c
-        let product = a_value.clone() * b_value;
-        product.value == c_value.value
+        a_value * b_value - c_value
     }
 
     /// Evaluates a linear combination of witness values.
@@ -151,6 +202,105 @@ impl<F: PrimeField> R1CSConstraint<F> {
     }
 }
 
+/// Two constraints are equal if their A, B and C vectors hold the same
+/// coefficients at the same indices.
+impl<F: PrimeField> PartialEq for R1CSConstraint<F> {
+    fn eq(&self, other: &Self) -> bool {
+        fn same_vector<F: PrimeField>(
+            x: &HashMap<usize, FieldWrapper<F>>,
+            y: &HashMap<usize, FieldWrapper<F>>,
+        ) -> bool {
+            x.len() == y.len()
+                && x.iter()
+                    .all(|(index, coeff)| y.get(index).is_some_and(|c| c.value == coeff.value))
+        }
+
+        same_vector(&self.a, &other.a)
+            && same_vector(&self.b, &other.b)
+            && same_vector(&self.c, &other.c)
+    }
+}
+
+impl<F: PrimeField> Eq for R1CSConstraint<F> {}
+
+/// Fluent builder returned by [`R1CSConstraint::builder`].
+///
+/// Each call behaves like the matching `add_*_variable` method, so setting
+/// the same index twice keeps the last coefficient.
+pub struct R1CSConstraintBuilder<F: PrimeField> {
+    constraint: R1CSConstraint<F>,
+}
+
+impl<F: PrimeField> R1CSConstraintBuilder<F> {
+    /// Adds a variable with coefficient to the A vector.
+    pub fn a(mut self, index: usize, coeff: FieldWrapper<F>) -> Self {
+        self.constraint.add_a_variable(index, coeff);
+        self
+    }
+
+    /// Adds a variable with coefficient to the B vector.
+    pub fn b(mut self, index: usize, coeff: FieldWrapper<F>) -> Self {
+        self.constraint.add_b_variable(index, coeff);
+        self
+    }
+
+    /// Adds a variable with coefficient to the C vector.
+    pub fn c(mut self, index: usize, coeff: FieldWrapper<F>) -> Self {
+        self.constraint.add_c_variable(index, coeff);
+        self
+    }
+
+    /// Returns the finished constraint.
+    pub fn build(self) -> R1CSConstraint<F> {
+        self.constraint
+    }
+}
+
+/// Converts an integer coefficient to a field element, used by [`r1cs!`](crate::r1cs).
+#[doc(hidden)]
+pub fn integer_coeff<F: PrimeField>(coeff: i64) -> FieldWrapper<F> {
+    let magnitude = FieldWrapper::<F>::from(coeff.unsigned_abs());
+    if coeff < 0 {
+        FieldWrapper::zero() - magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Builds an [`R1CSConstraint`] from `index: coefficient` pairs.
+///
+/// Coefficients are integer expressions (negative values are allowed and
+/// map to the field negation). Any of the three vectors may be left empty.
+///
+/// # Example
+/// ```
+/// use groth16_r1cs::r1cs;
+/// use groth16_r1cs::constraint::R1CSConstraint;
+/// use ark_bn254::Fr;
+///
+/// // (x + 5) * 1 = y, with witness layout [1, y, x]
+/// let constraint: R1CSConstraint<Fr> = r1cs! {
+///     a: {2: 1, 0: 5},
+///     b: {0: 1},
+///     c: {1: 1},
+/// };
+/// assert_eq!(constraint.unique_variable_count(), 3);
+/// ```
+#[macro_export]
+macro_rules! r1cs {
+    (
+        a: { $($a_index:tt : $a_coeff:expr),* $(,)? },
+        b: { $($b_index:tt : $b_coeff:expr),* $(,)? },
+        c: { $($c_index:tt : $c_coeff:expr),* $(,)? } $(,)?
+    ) => {
+        $crate::constraint::R1CSConstraint::builder()
+            $(.a($a_index, $crate::constraint::integer_coeff($a_coeff)))*
+            $(.b($b_index, $crate::constraint::integer_coeff($b_coeff)))*
+            $(.c($c_index, $crate::constraint::integer_coeff($c_coeff)))*
+            .build()
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +344,72 @@ mod tests {
         assert!(constraint.b.is_empty());
         assert!(constraint.c.is_empty());
     }
+
+    #[test]
+    fn test_builder_matches_add_methods() {
+        let mut expected = R1CSConstraint::<Fq>::new();
+        expected.add_a_variable(2, FieldWrapper::<Fq>::from(1u64));
+        expected.add_a_variable(0, FieldWrapper::<Fq>::from(5u64));
+        expected.add_b_variable(3, FieldWrapper::<Fq>::from(1u64));
+        expected.add_c_variable(
+            1,
+            FieldWrapper::<Fq>::zero() - FieldWrapper::<Fq>::from(2u64),
+        );
+
+        let built = R1CSConstraint::<Fq>::builder()
+            .a(2, FieldWrapper::<Fq>::from(1u64))
+            .a(0, FieldWrapper::<Fq>::from(5u64))
+            .b(3, FieldWrapper::<Fq>::from(1u64))
+            .c(
+                1,
+                FieldWrapper::<Fq>::zero() - FieldWrapper::<Fq>::from(2u64),
+            )
+            .build();
+        assert!(built == expected);
+
+        let from_macro: R1CSConstraint<Fq> = crate::r1cs! {
+            a: {2: 1, 0: 5},
+            b: {3: 1},
+            c: {1: -2},
+        };
+        assert!(from_macro == expected);
+
+        // A differing coefficient breaks equality
+        let other = R1CSConstraint::<Fq>::builder()
+            .a(2, FieldWrapper::<Fq>::from(1u64))
+            .a(0, FieldWrapper::<Fq>::from(6u64))
+            .b(3, FieldWrapper::<Fq>::from(1u64))
+            .c(
+                1,
+                FieldWrapper::<Fq>::zero() - FieldWrapper::<Fq>::from(2u64),
+            )
+            .build();
+        assert!(other != expected);
+    }
+
+    #[test]
+    fn test_residual() {
+        // Constraint: a * b = c
+        let mut constraint = R1CSConstraint::<Fq>::new();
+        constraint.add_a_variable(0, FieldWrapper::<Fq>::from(1u64));
+        constraint.add_b_variable(1, FieldWrapper::<Fq>::from(1u64));
+        constraint.add_c_variable(2, FieldWrapper::<Fq>::from(1u64));
+
+        let satisfied = vec![
+            FieldWrapper::<Fq>::from(3u64),
+            FieldWrapper::<Fq>::from(4u64),
+            FieldWrapper::<Fq>::from(12u64),
+        ];
+        assert_eq!(constraint.residual(&satisfied).value, Fq::from(0u64));
+        assert!(constraint.is_satisfied(&satisfied));
+
+        // c is 15 instead of 12: residual is 12 - 15 = -3
+        let unsatisfied = vec![
+            FieldWrapper::<Fq>::from(3u64),
+            FieldWrapper::<Fq>::from(4u64),
+            FieldWrapper::<Fq>::from(15u64),
+        ];
+        assert_eq!(constraint.residual(&unsatisfied).value, -Fq::from(3u64));
+        assert!(!constraint.is_satisfied(&unsatisfied));
+    }
 }
@@ -0,0 +1,18 @@
+use groth16_math::error::FieldError;
+use thiserror::Error;
+
+/// Errors that can occur when loading constraint systems from external data
+#[derive(Error, Debug)]
+pub enum R1csError {
+    #[error("Failed to read R1CS file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid R1CS JSON: {0}")]
+    InvalidJson(String),
+
+    #[error("Variable index {index} out of range for {num_variables} variables")]
+    VariableOutOfRange { index: usize, num_variables: usize },
+
+    #[error("Invalid coefficient: {0}")]
+    InvalidCoefficient(#[from] FieldError),
+}
@@ -3,6 +3,12 @@
 //! This crate provides R1CS representation and operations:
 //! - Constraint representation
 //! - Witness generation and satisfaction checking
+//! - Constraint systems and sparse matrix import/export
 
 pub mod constraint;
+pub mod error;
+pub mod system;
 pub mod witness;
+
+pub use error::R1csError;
+pub use system::load_r1cs_json;
@@ -0,0 +1,317 @@
+use crate::constraint::R1CSConstraint;
+use crate::error::R1csError;
+use ark_ff::PrimeField;
+use groth16_math::fields::FieldWrapper;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Sparse matrix rows: one row per constraint, each a list of (variable index, coefficient)
+pub type SparseMatrix<F> = Vec<Vec<(usize, F)>>;
+
+/// A complete R1CS instance: a list of constraints over a fixed number of variables.
+///
+/// Individual [`R1CSConstraint`]s only know about the variables they touch.
+/// `ConstraintSystem` ties them together with the total variable count so the
+/// system can be exported in the standard sparse A/B/C matrix form used by
+/// external ZK tooling.
+///
+/// # Example
+/// ```
+/// use groth16_r1cs::constraint::R1CSConstraint;
+/// use groth16_r1cs::system::ConstraintSystem;
+/// use groth16_math::fields::FieldWrapper;
+/// use ark_bn254::Fr;
+///
+/// // a * b = c with witness [1, c, a, b]
+/// let mut constraint = R1CSConstraint::<Fr>::new();
+/// constraint.add_a_variable(2, FieldWrapper::<Fr>::from(1u64));
+/// constraint.add_b_variable(3, FieldWrapper::<Fr>::from(1u64));
+/// constraint.add_c_variable(1, FieldWrapper::<Fr>::from(1u64));
+///
+/// let system = ConstraintSystem::new(vec![constraint], 4);
+/// let (a, b, c) = system.to_matrices();
+/// assert_eq!(a[0], vec![(2, Fr::from(1u64))]);
+/// ```
+#[derive(Clone)]
+pub struct ConstraintSystem<F: PrimeField> {
+    /// The constraints, in order
+    pub constraints: Vec<R1CSConstraint<F>>,
+    /// Total number of variables (including the constant 1 at index 0)
+    pub num_variables: usize,
+}
+
+impl<F: PrimeField> ConstraintSystem<F> {
+    /// Creates a constraint system from constraints and a total variable count.
+    pub fn new(constraints: Vec<R1CSConstraint<F>>, num_variables: usize) -> Self {
+        Self {
+            constraints,
+            num_variables,
+        }
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Exports the A, B and C matrices as per-constraint sparse rows.
+    ///
+    /// Each row lists the non-zero (variable index, coefficient) pairs of one
+    /// constraint, sorted by variable index so the output is deterministic.
+    pub fn to_matrices(&self) -> (SparseMatrix<F>, SparseMatrix<F>, SparseMatrix<F>) {
+        let mut a = Vec::with_capacity(self.constraints.len());
+        let mut b = Vec::with_capacity(self.constraints.len());
+        let mut c = Vec::with_capacity(self.constraints.len());
+
+        for constraint in &self.constraints {
+            a.push(sparse_row(&constraint.a));
+            b.push(sparse_row(&constraint.b));
+            c.push(sparse_row(&constraint.c));
+        }
+
+        (a, b, c)
+    }
+
+    /// Exports the system as JSON in the common R1CS layout:
+    ///
+    /// ```text
+    /// { "n_constraints": n, "n_vars": m, "A": [...], "B": [...], "C": [...] }
+    /// ```
+    ///
+    /// Each matrix is a list of rows, and each row a list of
+    /// `[variable_index, "coefficient"]` pairs. Coefficients are encoded as
+    /// decimal strings since they do not fit in a JSON number.
+    pub fn to_json_r1cs(&self) -> String {
+        let (a, b, c) = self.to_matrices();
+
+        let json = serde_json::json!({
+            "n_constraints": self.num_constraints(),
+            "n_vars": self.num_variables,
+            "A": matrix_to_json(&a),
+            "B": matrix_to_json(&b),
+            "C": matrix_to_json(&c),
+        });
+
+        json.to_string()
+    }
+
+    /// Parses a system from the JSON layout written by
+    /// [`to_json_r1cs`](Self::to_json_r1cs).
+    ///
+    /// `n_constraints` is optional; when present it must match the number
+    /// of rows. Coefficients are decimal strings below the field modulus.
+    ///
+    /// # Errors
+    /// * `R1csError::InvalidJson` - Malformed JSON or mismatched matrix shapes
+    /// * `R1csError::VariableOutOfRange` - A row references index ≥ `n_vars`
+    /// * `R1csError::InvalidCoefficient` - A coefficient is not a valid field element
+    pub fn from_json_r1cs(json: &str) -> Result<Self, R1csError> {
+        let parsed: JsonR1cs =
+            serde_json::from_str(json).map_err(|e| R1csError::InvalidJson(e.to_string()))?;
+
+        let rows = parsed.a.len();
+        if parsed.b.len() != rows || parsed.c.len() != rows {
+            return Err(R1csError::InvalidJson(format!(
+                "A, B and C have {}, {} and {} rows",
+                rows,
+                parsed.b.len(),
+                parsed.c.len()
+            )));
+        }
+        if let Some(n_constraints) = parsed.n_constraints {
+            if n_constraints != rows {
+                return Err(R1csError::InvalidJson(format!(
+                    "n_constraints is {} but the matrices have {} rows",
+                    n_constraints, rows
+                )));
+            }
+        }
+
+        let mut constraints = Vec::with_capacity(rows);
+        for ((a, b), c) in parsed.a.iter().zip(&parsed.b).zip(&parsed.c) {
+            let mut constraint = R1CSConstraint::new();
+            for (index, coeff) in parse_row(a, parsed.n_vars)? {
+                constraint.add_a_variable(index, coeff);
+            }
+            for (index, coeff) in parse_row(b, parsed.n_vars)? {
+                constraint.add_b_variable(index, coeff);
+            }
+            for (index, coeff) in parse_row(c, parsed.n_vars)? {
+                constraint.add_c_variable(index, coeff);
+            }
+            constraints.push(constraint);
+        }
+
+        Ok(Self::new(constraints, parsed.n_vars))
+    }
+}
+
+/// Loads constraints from a JSON R1CS file, see [`ConstraintSystem::from_json_r1cs`].
+///
+/// Lets circuits be defined as data instead of Rust types.
+///
+/// # Returns
+/// The constraints and the total number of variables, ready for `r1cs_to_qap`.
+pub fn load_r1cs_json<F: PrimeField>(
+    path: impl AsRef<Path>,
+) -> Result<(Vec<R1CSConstraint<F>>, usize), R1csError> {
+    let json = std::fs::read_to_string(path)?;
+    let system = ConstraintSystem::from_json_r1cs(&json)?;
+    Ok((system.constraints, system.num_variables))
+}
+
+/// On-disk shape of [`ConstraintSystem::to_json_r1cs`]
+#[derive(Deserialize)]
+struct JsonR1cs {
+    n_constraints: Option<usize>,
+    n_vars: usize,
+    #[serde(rename = "A")]
+    a: Vec<Vec<(usize, String)>>,
+    #[serde(rename = "B")]
+    b: Vec<Vec<(usize, String)>>,
+    #[serde(rename = "C")]
+    c: Vec<Vec<(usize, String)>>,
+}
+
+/// Decodes one sparse row, checking indices and coefficients
+fn parse_row<F: PrimeField>(
+    row: &[(usize, String)],
+    num_variables: usize,
+) -> Result<Vec<(usize, FieldWrapper<F>)>, R1csError> {
+    row.iter()
+        .map(|(index, coeff)| {
+            if *index >= num_variables {
+                return Err(R1csError::VariableOutOfRange {
+                    index: *index,
+                    num_variables,
+                });
+            }
+            Ok((*index, FieldWrapper::from_decimal_str(coeff)?))
+        })
+        .collect()
+}
+
+/// Converts one sparse constraint vector into a sorted row
+fn sparse_row<F: PrimeField>(coeffs: &HashMap<usize, FieldWrapper<F>>) -> Vec<(usize, F)> {
+    let mut row: Vec<(usize, F)> = coeffs
+        .iter()
+        .filter(|(_, coeff)| !coeff.value.is_zero())
+        .map(|(idx, coeff)| (*idx, coeff.value))
+        .collect();
+    row.sort_by_key(|(idx, _)| *idx);
+    row
+}
+
+/// Encodes a sparse matrix as nested JSON arrays with decimal coefficients
+fn matrix_to_json<F: PrimeField>(matrix: &SparseMatrix<F>) -> serde_json::Value {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(idx, coeff)| serde_json::json!([idx, coeff.into_bigint().to_string()]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    /// The R1CS of the cubic circuit a·x³ + b·x² + c·x + d = y, over the
+    /// witness [1, a, b, c, d, y, x, x², x³, a·x³, b·x², c·x]
+    fn cubic_system() -> ConstraintSystem<Fr> {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/cubic.json");
+        let (constraints, num_variables) = load_r1cs_json::<Fr>(path).unwrap();
+        ConstraintSystem::new(constraints, num_variables)
+    }
+
+    #[test]
+    fn test_to_matrices_dimensions() {
+        let system = cubic_system();
+        let (a, b, c) = system.to_matrices();
+
+        assert_eq!(a.len(), 6);
+        assert_eq!(b.len(), 6);
+        assert_eq!(c.len(), 6);
+
+        // Spot-check known coefficients: x · x = x², then
+        // (a·x³ + b·x² + c·x + d) · 1 = y
+        assert_eq!(a[0], vec![(6, Fr::from(1u64))]);
+        assert_eq!(c[0], vec![(7, Fr::from(1u64))]);
+        assert_eq!(
+            a[5],
+            [4, 9, 10, 11].map(|idx| (idx, Fr::from(1u64))).to_vec()
+        );
+        assert_eq!(b[5], vec![(0, Fr::from(1u64))]);
+        assert_eq!(c[5], vec![(5, Fr::from(1u64))]);
+    }
+
+    #[test]
+    fn test_to_json_r1cs() {
+        let system = cubic_system();
+        let json: serde_json::Value = serde_json::from_str(&system.to_json_r1cs()).unwrap();
+
+        assert_eq!(json["n_constraints"], 6);
+        assert_eq!(json["n_vars"], 12);
+        assert_eq!(json["A"].as_array().unwrap().len(), 6);
+        assert_eq!(json["C"][5][0], serde_json::json!([5, "1"]));
+    }
+
+    #[test]
+    fn test_json_r1cs_round_trip() {
+        let system = cubic_system();
+        let parsed = ConstraintSystem::<Fr>::from_json_r1cs(&system.to_json_r1cs()).unwrap();
+
+        assert_eq!(parsed.num_variables, 12);
+        assert!(parsed.constraints == system.constraints);
+
+        // 2x³ + 3x² + 4x + 5 = 41 at x = 2
+        let witness: Vec<_> = [1u64, 2, 3, 4, 5, 41, 2, 4, 8, 16, 12, 8]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+        assert!(parsed.constraints.iter().all(|c| c.is_satisfied(&witness)));
+    }
+
+    #[test]
+    fn test_load_r1cs_json_multiplier() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/multiplier.json");
+        let (constraints, num_variables) = load_r1cs_json::<Fr>(path).unwrap();
+
+        assert_eq!(num_variables, 4);
+        assert_eq!(constraints.len(), 1);
+
+        // Witness [1, c, a, b] for 3 × 4 = 12
+        let witness: Vec<_> = [1u64, 12, 3, 4]
+            .into_iter()
+            .map(FieldWrapper::<Fr>::from)
+            .collect();
+        assert!(constraints[0].is_satisfied(&witness));
+    }
+
+    #[test]
+    fn test_from_json_r1cs_rejects_bad_input() {
+        let parse = ConstraintSystem::<Fr>::from_json_r1cs;
+
+        assert!(matches!(parse("not json"), Err(R1csError::InvalidJson(_))));
+        assert!(matches!(
+            parse(r#"{"n_vars": 4, "A": [[]], "B": [], "C": [[]]}"#),
+            Err(R1csError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            parse(r#"{"n_vars": 4, "A": [[[4, "1"]]], "B": [[]], "C": [[]]}"#),
+            Err(R1csError::VariableOutOfRange {
+                index: 4,
+                num_variables: 4
+            })
+        ));
+        assert!(matches!(
+            parse(r#"{"n_vars": 4, "A": [[[1, "-1"]]], "B": [[]], "C": [[]]}"#),
+            Err(R1csError::InvalidCoefficient(_))
+        ));
+    }
+}